@@ -1,9 +1,10 @@
 use std::io::IoSlice;
 
-use crate::bytes::{Bytes, BytesMut, Chain, Take};
+use crate::bytes::varint::{self, VarintError};
+use crate::bytes::{Bytes, BytesMut, Chain, Reader, Take};
 
 macro_rules! fn_get_int {
-    ($ty:ident, $m1:ident, $m2:ident, $f:ident, $doc:literal) => {
+    ($ty:ident, $m1:ident, $m2:ident, $m3:ident, $m4:ident, $f:ident, $doc:literal) => {
         #[doc = concat!("Get `", stringify!($ty), "` in ", $doc)]
         ///
         /// # Panics
@@ -19,50 +20,155 @@ macro_rules! fn_get_int {
 
         #[doc = concat!("Get `", stringify!($ty), "` in ", $doc)]
         ///
-        /// Returns `None` if there is not enough remaining bytes.
+        /// Returns `None` if there is not enough remaining bytes, `self` is not advanced.
         fn $m2(&mut self) -> Option<$ty> {
             const SIZE: usize = size_of::<$ty>();
+            if self.remaining() < SIZE {
+                return None;
+            }
             let mut chunk = [0u8; SIZE];
             self.copy_to_slice(&mut chunk[..]);
             Some(<$ty>::$f(chunk))
         }
+
+        #[doc = concat!("Get `", stringify!($ty), "` in ", $doc)]
+        ///
+        /// Returns [`TryGetError`] if there is not enough remaining bytes, `self` is not advanced.
+        #[inline]
+        fn $m3(&mut self) -> Result<$ty, TryGetError> {
+            match self.$m2() {
+                Some(ok) => Ok(ok),
+                None => Err(TryGetError::new(size_of::<$ty>(), self.remaining())),
+            }
+        }
+
+        #[doc = concat!("Peek `", stringify!($ty), "` in ", $doc, " without advancing.")]
+        ///
+        /// Returns `None` if there is not enough remaining bytes.
+        #[inline]
+        fn $m4(&self) -> Option<$ty> {
+            let mut chunk = [0u8; size_of::<$ty>()];
+            self.peek_slice(&mut chunk[..]).then(|| <$ty>::$f(chunk))
+        }
     };
-    ($f:ident, $doc:literal, $($ty:ident, $m1:ident, $m2:ident),* $(,)?) => {
-        $( fn_get_int!($ty, $m1, $m2, $f, $doc);)*
+    ($f:ident, $doc:literal, $($ty:ident, $m1:ident, $m2:ident, $m3:ident, $m4:ident),* $(,)?) => {
+        $( fn_get_int!($ty, $m1, $m2, $m3, $m4, $f, $doc);)*
     };
     () => {
         fn_get_int!(
             from_be_bytes, "big endian",
-            u16, get_u16, try_get_u16,
-            i16, get_i16, try_get_i16,
-            u32, get_u32, try_get_u32,
-            i32, get_i32, try_get_i32,
-            u64, get_u64, try_get_u64,
-            i64, get_i64, try_get_i64,
-            u128, get_u128, try_get_u128,
-            i128, get_i128, try_get_i128,
+            u16, get_u16, try_get_u16, checked_get_u16, peek_u16,
+            i16, get_i16, try_get_i16, checked_get_i16, peek_i16,
+            u32, get_u32, try_get_u32, checked_get_u32, peek_u32,
+            i32, get_i32, try_get_i32, checked_get_i32, peek_i32,
+            u64, get_u64, try_get_u64, checked_get_u64, peek_u64,
+            i64, get_i64, try_get_i64, checked_get_i64, peek_i64,
+            u128, get_u128, try_get_u128, checked_get_u128, peek_u128,
+            i128, get_i128, try_get_i128, checked_get_i128, peek_i128,
+            f32, get_f32, try_get_f32, checked_get_f32, peek_f32,
+            f64, get_f64, try_get_f64, checked_get_f64, peek_f64,
         );
         fn_get_int!(
             from_le_bytes, "little endian",
-            u16, get_u16_le, try_get_u16_le,
-            i16, get_i16_le, try_get_i16_le,
-            u32, get_u32_le, try_get_u32_le,
-            i32, get_i32_le, try_get_i32_le,
-            u64, get_u64_le, try_get_u64_le,
-            i64, get_i64_le, try_get_i64_le,
-            u128, get_u128_le, try_get_u128_le,
-            i128, get_i128_le, try_get_i128_le,
+            u16, get_u16_le, try_get_u16_le, checked_get_u16_le, peek_u16_le,
+            i16, get_i16_le, try_get_i16_le, checked_get_i16_le, peek_i16_le,
+            u32, get_u32_le, try_get_u32_le, checked_get_u32_le, peek_u32_le,
+            i32, get_i32_le, try_get_i32_le, checked_get_i32_le, peek_i32_le,
+            u64, get_u64_le, try_get_u64_le, checked_get_u64_le, peek_u64_le,
+            i64, get_i64_le, try_get_i64_le, checked_get_i64_le, peek_i64_le,
+            u128, get_u128_le, try_get_u128_le, checked_get_u128_le, peek_u128_le,
+            i128, get_i128_le, try_get_i128_le, checked_get_i128_le, peek_i128_le,
+            f32, get_f32_le, try_get_f32_le, checked_get_f32_le, peek_f32_le,
+            f64, get_f64_le, try_get_f64_le, checked_get_f64_le, peek_f64_le,
         );
         fn_get_int!(
             from_ne_bytes, "native endian",
-            u16, get_u16_ne, try_get_u16_ne,
-            i16, get_i16_ne, try_get_i16_ne,
-            u32, get_u32_ne, try_get_u32_ne,
-            i32, get_i32_ne, try_get_i32_ne,
-            u64, get_u64_ne, try_get_u64_ne,
-            i64, get_i64_ne, try_get_i64_ne,
-            u128, get_u128_ne, try_get_u128_ne,
-            i128, get_i128_ne, try_get_i128_ne,
+            u16, get_u16_ne, try_get_u16_ne, checked_get_u16_ne, peek_u16_ne,
+            i16, get_i16_ne, try_get_i16_ne, checked_get_i16_ne, peek_i16_ne,
+            u32, get_u32_ne, try_get_u32_ne, checked_get_u32_ne, peek_u32_ne,
+            i32, get_i32_ne, try_get_i32_ne, checked_get_i32_ne, peek_i32_ne,
+            u64, get_u64_ne, try_get_u64_ne, checked_get_u64_ne, peek_u64_ne,
+            i64, get_i64_ne, try_get_i64_ne, checked_get_i64_ne, peek_i64_ne,
+            u128, get_u128_ne, try_get_u128_ne, checked_get_u128_ne, peek_u128_ne,
+            i128, get_i128_ne, try_get_i128_ne, checked_get_i128_ne, peek_i128_ne,
+            f32, get_f32_ne, try_get_f32_ne, checked_get_f32_ne, peek_f32_ne,
+            f64, get_f64_ne, try_get_f64_ne, checked_get_f64_ne, peek_f64_ne,
+        );
+    };
+}
+
+macro_rules! fn_get_uint {
+    (
+        $uint:ident, $try_uint:ident, $int:ident, $try_int:ident,
+        $f:ident, $be:expr, $doc:literal
+    ) => {
+        #[doc = concat!("Get unsigned integer of `nbytes` bytes in ", $doc, ".")]
+        ///
+        /// # Panics
+        ///
+        /// Panics if `nbytes > 8`, or there is not enough remaining bytes.
+        #[inline]
+        fn $uint(&mut self, nbytes: usize) -> u64 {
+            match self.$try_uint(nbytes) {
+                Some(ok) => ok,
+                None => remaining_fail(self.remaining(), nbytes)
+            }
+        }
+
+        #[doc = concat!("Get unsigned integer of `nbytes` bytes in ", $doc, ".")]
+        ///
+        /// Returns `None` if there is not enough remaining bytes, `self` is not advanced.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `nbytes > 8`.
+        fn $try_uint(&mut self, nbytes: usize) -> Option<u64> {
+            let mut chunk = [0u8; 8];
+            let range = uint_range(nbytes, $be);
+            if self.remaining() < nbytes {
+                return None;
+            }
+            self.copy_to_slice(&mut chunk[range]);
+            Some(u64::$f(chunk))
+        }
+
+        #[doc = concat!("Get signed integer of `nbytes` bytes in ", $doc, ".")]
+        ///
+        /// # Panics
+        ///
+        /// Panics if `nbytes > 8`, or there is not enough remaining bytes.
+        #[inline]
+        fn $int(&mut self, nbytes: usize) -> i64 {
+            match self.$try_int(nbytes) {
+                Some(ok) => ok,
+                None => remaining_fail(self.remaining(), nbytes)
+            }
+        }
+
+        #[doc = concat!("Get signed integer of `nbytes` bytes in ", $doc, ".")]
+        ///
+        /// Returns `None` if there is not enough remaining bytes, `self` is not advanced.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `nbytes > 8`.
+        #[inline]
+        fn $try_int(&mut self, nbytes: usize) -> Option<i64> {
+            self.$try_uint(nbytes).map(|n| sign_extend(n, nbytes))
+        }
+    };
+    () => {
+        fn_get_uint!(
+            get_uint, try_get_uint, get_int, try_get_int,
+            from_be_bytes, true, "big endian"
+        );
+        fn_get_uint!(
+            get_uint_le, try_get_uint_le, get_int_le, try_get_int_le,
+            from_le_bytes, false, "little endian"
+        );
+        fn_get_uint!(
+            get_uint_ne, try_get_uint_ne, get_int_ne, try_get_int_ne,
+            from_ne_bytes, cfg!(target_endian = "big"), "native endian"
         );
     };
 }
@@ -142,6 +248,10 @@ pub trait Buf {
     /// Implementations should also take care to properly handle being called with `dst` being a
     /// zero length slice.
     ///
+    /// The default implementation of [`peek_at`] reads from the slices returned by this function,
+    /// so a `Buf` backed by disjoint slices should override either this function or `peek_at`.
+    ///
+    /// [`peek_at`]: Buf::peek_at
     /// [`writev`]: http://man7.org/linux/man-pages/man2/readv.2.html
     #[inline]
     fn chunks_vectored<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
@@ -187,6 +297,78 @@ pub trait Buf {
         }
     }
 
+    /// Copies bytes starting at `offset` from the current position into `dst`, without
+    /// advancing.
+    ///
+    /// Returns `false` if there is not enough remaining bytes to fill `dst`, in which case the
+    /// content of `dst` is unspecified.
+    ///
+    /// # Panics
+    ///
+    /// The default implementation panics if there is enough remaining bytes, but they are not
+    /// covered by the first 64 slices returned by [`chunks_vectored`].
+    ///
+    /// # Implementation notes
+    ///
+    /// The default implementation reads from the slices returned by [`chunks_vectored`], whose
+    /// default only returns [`chunk`]. Implementations backed by disjoint slices must override
+    /// either `chunks_vectored` or this method, the latter is preferred for more than a few
+    /// slices.
+    ///
+    /// [`chunk`]: Buf::chunk
+    /// [`chunks_vectored`]: Buf::chunks_vectored
+    fn peek_at(&self, offset: usize, dst: &mut [u8]) -> bool {
+        if offset.checked_add(dst.len()).is_none_or(|end| end > self.remaining()) {
+            return false;
+        }
+        if let Some(src) = self.chunk().get(offset..offset + dst.len()) {
+            dst.copy_from_slice(src);
+            return true;
+        }
+
+        const LEN: usize = 64;
+        let mut slices = [IoSlice::new(&[]); LEN];
+        let cnt = self.chunks_vectored(&mut slices);
+        assert!(
+            peek_slices(slices[..cnt].iter().map(|e| &**e), offset, dst),
+            "remaining bytes is not covered by `Buf::chunks_vectored`, \
+            `Buf::peek_at` must be implemented"
+        );
+        true
+    }
+
+    /// Copies bytes from the current position into `dst`, without advancing.
+    ///
+    /// Returns `false` if there is not enough remaining bytes to fill `dst`, in which case the
+    /// content of `dst` is unspecified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`peek_at`] panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tcio::bytes::Buf;
+    ///
+    /// let mut buf = (&b"\x00\x03"[..]).chain(&b"abc"[..]);
+    ///
+    /// let mut header = [0u8; 2];
+    /// assert!(buf.peek_slice(&mut header));
+    /// let len = u16::from_be_bytes(header) as usize;
+    ///
+    /// if buf.remaining() >= 2 + len {
+    ///     buf.advance(2);
+    ///     assert_eq!(buf.copy_to_bytes(len), b"abc"[..]);
+    /// }
+    /// ```
+    ///
+    /// [`peek_at`]: Buf::peek_at
+    #[inline]
+    fn peek_slice(&self, dst: &mut [u8]) -> bool {
+        self.peek_at(0, dst)
+    }
+
     /// Consumes `len` bytes inside self and returns new instance of [`Bytes`] with
     /// this data.
     ///
@@ -228,6 +410,27 @@ pub trait Buf {
         Chain::new(self, next)
     }
 
+    /// Creates an adaptor which implements [`std::io::Read`] and [`std::io::BufRead`] for `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::BufRead;
+    /// use tcio::bytes::Buf;
+    ///
+    /// let buf = (&b"Hello "[..]).chain(&b"World!\n"[..]);
+    /// let mut line = String::new();
+    /// buf.reader().read_line(&mut line).unwrap();
+    /// assert_eq!(line, "Hello World!\n");
+    /// ```
+    #[inline]
+    fn reader(self) -> Reader<Self>
+    where
+        Self: Sized,
+    {
+        Reader::new(self)
+    }
+
     /// Get `u8`.
     ///
     /// `self` is advanced by 1.
@@ -255,6 +458,27 @@ pub trait Buf {
         Some(b)
     }
 
+    /// Get `u8`.
+    ///
+    /// `self` is advanced by 1.
+    ///
+    /// Returns [`TryGetError`] if current buf is empty.
+    #[inline]
+    fn checked_get_u8(&mut self) -> Result<u8, TryGetError> {
+        match self.try_get_u8() {
+            Some(ok) => Ok(ok),
+            None => Err(TryGetError::new(size_of::<u8>(), self.remaining())),
+        }
+    }
+
+    /// Peek `u8` without advancing.
+    ///
+    /// Returns `None` if current buf is empty.
+    #[inline]
+    fn peek_u8(&self) -> Option<u8> {
+        self.chunk().first().copied()
+    }
+
     /// Get `i8`.
     ///
     /// `self` is advanced by 1.
@@ -282,7 +506,150 @@ pub trait Buf {
         Some(b as i8)
     }
 
+    /// Get `i8`.
+    ///
+    /// `self` is advanced by 1.
+    ///
+    /// Returns [`TryGetError`] if current buf is empty.
+    #[inline]
+    fn checked_get_i8(&mut self) -> Result<i8, TryGetError> {
+        match self.try_get_i8() {
+            Some(ok) => Ok(ok),
+            None => Err(TryGetError::new(size_of::<i8>(), self.remaining())),
+        }
+    }
+
+    /// Peek `i8` without advancing.
+    ///
+    /// Returns `None` if current buf is empty.
+    #[inline]
+    fn peek_i8(&self) -> Option<i8> {
+        self.chunk().first().map(|&b| b as i8)
+    }
+
     fn_get_int!();
+
+    fn_get_uint!();
+
+    /// Get unsigned LEB128 variable-length integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not enough remaining bytes or the value overflows `u64`.
+    #[inline]
+    fn get_uvarint(&mut self) -> u64 {
+        match self.checked_get_uvarint() {
+            Ok(ok) => ok,
+            Err(err) => varint_fail(err),
+        }
+    }
+
+    /// Get unsigned LEB128 variable-length integer.
+    ///
+    /// Returns `None` if there is not enough remaining bytes or the value overflows `u64`,
+    /// `self` is not advanced.
+    ///
+    /// Note that malformed input which can never decode also returns `None`, a parser which
+    /// retries on `None` should use [`checked_get_uvarint`] to detect it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tcio::bytes::Buf;
+    ///
+    /// let mut buf = &b"\xac\x02\x80"[..];
+    /// assert_eq!(buf.try_get_uvarint(), Some(300));
+    /// assert_eq!(buf.try_get_uvarint(), None);
+    /// assert_eq!(buf.remaining(), 1);
+    /// ```
+    ///
+    /// [`checked_get_uvarint`]: Buf::checked_get_uvarint
+    #[inline]
+    fn try_get_uvarint(&mut self) -> Option<u64> {
+        self.checked_get_uvarint().ok()
+    }
+
+    /// Get unsigned LEB128 variable-length integer.
+    ///
+    /// Returns [`VarintError::Incomplete`] if there is not enough remaining bytes, or
+    /// [`VarintError::Overflow`] if the value overflows `u64`, `self` is not advanced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value spans multiple chunks and [`peek_at`] panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tcio::bytes::Buf;
+    /// use tcio::bytes::error::VarintError;
+    ///
+    /// let mut buf = &b"\xac\x02\x80"[..];
+    /// assert_eq!(buf.checked_get_uvarint(), Ok(300));
+    /// assert_eq!(buf.checked_get_uvarint(), Err(VarintError::Incomplete));
+    ///
+    /// let mut buf = &[0xff; 10][..];
+    /// assert_eq!(buf.checked_get_uvarint(), Err(VarintError::Overflow));
+    /// ```
+    ///
+    /// [`peek_at`]: Buf::peek_at
+    fn checked_get_uvarint(&mut self) -> Result<u64, VarintError> {
+        let (n, len) = match varint::decode(self.chunk()) {
+            Ok(ok) => ok,
+            Err(VarintError::Overflow) => return Err(VarintError::Overflow),
+            Err(VarintError::Incomplete) => {
+                // the value may continue in the next chunks
+                let len = self.remaining().min(varint::MAX_LEN);
+                if len <= self.chunk().len() {
+                    return Err(VarintError::Incomplete);
+                }
+                // `len` is within the remaining bytes, so a failed peek is not `Incomplete`
+                let mut buf = [0u8; varint::MAX_LEN];
+                if !self.peek_slice(&mut buf[..len]) {
+                    panic!("`Buf::peek_at` failed within the remaining bytes");
+                }
+                varint::decode(&buf[..len])?
+            }
+        };
+        self.advance(len);
+        Ok(n)
+    }
+
+    /// Get zigzag encoded signed LEB128 variable-length integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not enough remaining bytes or the value overflows 64 bits.
+    #[inline]
+    fn get_ivarint(&mut self) -> i64 {
+        match self.checked_get_ivarint() {
+            Ok(ok) => ok,
+            Err(err) => varint_fail(err),
+        }
+    }
+
+    /// Get zigzag encoded signed LEB128 variable-length integer.
+    ///
+    /// Returns `None` if there is not enough remaining bytes or the value overflows 64 bits,
+    /// `self` is not advanced.
+    ///
+    /// Note that malformed input which can never decode also returns `None`, a parser which
+    /// retries on `None` should use [`checked_get_ivarint`] to detect it.
+    ///
+    /// [`checked_get_ivarint`]: Buf::checked_get_ivarint
+    #[inline]
+    fn try_get_ivarint(&mut self) -> Option<i64> {
+        self.checked_get_ivarint().ok()
+    }
+
+    /// Get zigzag encoded signed LEB128 variable-length integer.
+    ///
+    /// Returns [`VarintError::Incomplete`] if there is not enough remaining bytes, or
+    /// [`VarintError::Overflow`] if the value overflows 64 bits, `self` is not advanced.
+    #[inline]
+    fn checked_get_ivarint(&mut self) -> Result<i64, VarintError> {
+        self.checked_get_uvarint().map(varint::zigzag_decode)
+    }
 }
 
 // Buf is dyn compatible.
@@ -412,6 +779,8 @@ macro_rules! delegate_blanket_impl {
             -> usize { T::chunks_vectored(self, dst) }
         #[inline] fn has_remaining(&self) -> bool { T::has_remaining(self) }
         #[inline] fn copy_to_slice(&mut self, dst: &mut [u8]) { T::copy_to_slice(self, dst) }
+        #[inline] fn peek_at(&self, offset: usize, dst: &mut [u8])
+            -> bool { T::peek_at(self, offset, dst) }
         #[inline] fn copy_to_bytes(&mut self, len: usize) -> Bytes { T::copy_to_bytes(self, len) }
     };
 }
@@ -424,6 +793,102 @@ impl<T: Buf + ?Sized> Buf for Box<T> {
     delegate_blanket_impl!();
 }
 
+/// Returns the range of `[u8; 8]` where an integer of `nbytes` bytes is placed.
+///
+/// # Panics
+///
+/// Panics if `nbytes > 8`.
+pub(crate) fn uint_range(nbytes: usize, big_endian: bool) -> std::ops::Range<usize> {
+    assert!(nbytes <= 8, "integer width ({nbytes}) is larger than 8 bytes");
+    if big_endian {
+        8 - nbytes..8
+    } else {
+        0..nbytes
+    }
+}
+
+/// Sign extend the lower `nbytes` bytes of `n`.
+fn sign_extend(n: u64, nbytes: usize) -> i64 {
+    match nbytes {
+        0 => 0,
+        _ => {
+            let shift = 64 - nbytes as u32 * 8;
+            ((n << shift) as i64) >> shift
+        }
+    }
+}
+
+/// Copies bytes starting at `offset` of the concatenated `slices` into `dst`.
+///
+/// Returns `false` if the slices is not enough to fill `dst`.
+pub(crate) fn peek_slices<'a>(
+    slices: impl Iterator<Item = &'a [u8]>,
+    mut offset: usize,
+    dst: &mut [u8],
+) -> bool {
+    let mut filled = 0;
+    for slice in slices {
+        if filled == dst.len() {
+            break;
+        }
+        let Some(src) = slice.get(offset..) else {
+            offset -= slice.len();
+            continue;
+        };
+        offset = 0;
+
+        let cnt = src.len().min(dst.len() - filled);
+        dst[filled..filled + cnt].copy_from_slice(&src[..cnt]);
+        filled += cnt;
+    }
+    filled == dst.len()
+}
+
+// ===== Error =====
+
+/// An error returned when there is not enough remaining bytes in a [`Buf`].
+///
+/// The buffer is not advanced when this error is returned, so parsing can be retried from the
+/// beginning after more bytes are read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryGetError {
+    /// The number of bytes requested.
+    pub requested: usize,
+    /// The number of bytes available.
+    pub available: usize,
+}
+
+impl TryGetError {
+    const fn new(requested: usize, available: usize) -> Self {
+        Self { requested, available }
+    }
+
+    /// Returns the number of additional bytes required to fulfill the request.
+    #[inline]
+    pub const fn missing(&self) -> usize {
+        self.requested.saturating_sub(self.available)
+    }
+}
+
+impl std::error::Error for TryGetError { }
+
+impl std::fmt::Display for TryGetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "not enough bytes remaining, requested {} but only {} available",
+            self.requested, self.available,
+        )
+    }
+}
+
+impl From<TryGetError> for std::io::Error {
+    #[inline]
+    fn from(value: TryGetError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::UnexpectedEof, value)
+    }
+}
+
 // ===== panics =====
 
 // The panic code path was put into a cold function to not bloat the call site.
@@ -436,3 +901,10 @@ fn remaining_fail(src_len: usize, req_len: usize) -> ! {
         "source remaining ({src_len}) is less than requested length ({req_len})"
     )
 }
+
+#[cfg_attr(not(panic = "immediate-abort"), inline(never), cold)]
+#[cfg_attr(panic = "immediate-abort", inline)]
+#[track_caller]
+fn varint_fail(err: VarintError) -> ! {
+    panic!("failed to get varint: {err}")
+}
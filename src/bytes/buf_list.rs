@@ -0,0 +1,264 @@
+use std::collections::VecDeque;
+use std::collections::vec_deque;
+use std::io::IoSlice;
+
+use crate::bytes::{Buf, BufMut, Bytes, BytesMut};
+
+/// A segmented buffer of a queue of [`Bytes`].
+///
+/// `BufList` provides a continuous [`Buf`] view over multiple segments without concatenating
+/// them. Empty segments are never stored.
+///
+/// # Examples
+///
+/// ```
+/// use tcio::bytes::{Buf, BufList, Bytes};
+///
+/// let mut list = BufList::new();
+/// list.push_back(Bytes::from_static(b"Hello"));
+/// list.push_back(Bytes::from_static(b" World!"));
+/// list.push_front(Bytes::from_static(b"> "));
+///
+/// assert_eq!(list.remaining(), 14);
+/// assert_eq!(list.segments(), 3);
+///
+/// let head = list.split_to(7);
+/// assert_eq!(head.segments(), 2);
+/// assert_eq!(list.copy_to_bytes(7), b" World!"[..]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BufList {
+    bufs: VecDeque<Bytes>,
+    remaining: usize,
+}
+
+impl BufList {
+    /// Creates new empty [`BufList`].
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            bufs: VecDeque::new(),
+            remaining: 0,
+        }
+    }
+
+    /// Creates new empty [`BufList`] with at least specified number of segments capacity.
+    #[inline]
+    pub fn with_capacity(segments: usize) -> Self {
+        Self {
+            bufs: VecDeque::with_capacity(segments),
+            remaining: 0,
+        }
+    }
+
+    /// Returns the total number of bytes in all segments.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.remaining
+    }
+
+    /// Returns `true` if there is no bytes in the list.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// Returns the number of segments.
+    #[inline]
+    pub fn segments(&self) -> usize {
+        self.bufs.len()
+    }
+
+    /// Returns an iterator over the segments.
+    #[inline]
+    pub fn iter(&self) -> vec_deque::Iter<'_, Bytes> {
+        self.bufs.iter()
+    }
+
+    /// Appends a segment to the back of the list.
+    ///
+    /// Empty `bytes` is discarded.
+    #[inline]
+    pub fn push_back(&mut self, bytes: Bytes) {
+        if !bytes.is_empty() {
+            self.remaining += bytes.len();
+            self.bufs.push_back(bytes);
+        }
+    }
+
+    /// Prepends a segment to the front of the list.
+    ///
+    /// Empty `bytes` is discarded.
+    #[inline]
+    pub fn push_front(&mut self, bytes: Bytes) {
+        if !bytes.is_empty() {
+            self.remaining += bytes.len();
+            self.bufs.push_front(bytes);
+        }
+    }
+
+    /// Removes the first segment and returns it, or `None` if the list is empty.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<Bytes> {
+        let bytes = self.bufs.pop_front()?;
+        self.remaining -= bytes.len();
+        Some(bytes)
+    }
+
+    /// Removes the last segment and returns it, or `None` if the list is empty.
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<Bytes> {
+        let bytes = self.bufs.pop_back()?;
+        self.remaining -= bytes.len();
+        Some(bytes)
+    }
+
+    /// Splits the list into two at the given index.
+    ///
+    /// Afterwards `self` contains bytes `[at, len)`, and the returned `BufList` contains bytes
+    /// `[0, at)`. A segment which contains the index is split without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()`.
+    pub fn split_to(&mut self, at: usize) -> BufList {
+        assert!(at <= self.remaining, "split_to out of bounds: {at:?} <= {:?}", self.remaining);
+
+        let mut head = BufList::new();
+        let mut left = at;
+        while left != 0 {
+            let front = self.bufs.front_mut().expect("`at` is in bounds");
+            if front.len() <= left {
+                left -= front.len();
+                head.push_back(self.bufs.pop_front().expect("front exists"));
+            } else {
+                head.push_back(front.split_to(left));
+                left = 0;
+            }
+        }
+        self.remaining -= at;
+        head
+    }
+
+    /// Removes all segments.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bufs.clear();
+        self.remaining = 0;
+    }
+}
+
+impl Buf for BufList {
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        match self.bufs.front() {
+            Some(bytes) => bytes.as_slice(),
+            None => &[],
+        }
+    }
+
+    fn advance(&mut self, mut cnt: usize) {
+        assert!(cnt <= self.remaining, "out of bounds");
+        self.remaining -= cnt;
+
+        while cnt != 0 {
+            let front = self.bufs.front_mut().expect("`cnt` is in bounds");
+            if front.len() <= cnt {
+                cnt -= front.len();
+                self.bufs.pop_front();
+            } else {
+                front.advance(cnt);
+                cnt = 0;
+            }
+        }
+    }
+
+    fn chunks_vectored<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        let mut cnt = 0;
+        for (slot, bytes) in dst.iter_mut().zip(&self.bufs) {
+            *slot = IoSlice::new(bytes.as_slice());
+            cnt += 1;
+        }
+        cnt
+    }
+
+    fn peek_at(&self, offset: usize, dst: &mut [u8]) -> bool {
+        if offset.checked_add(dst.len()).is_none_or(|end| end > self.remaining) {
+            return false;
+        }
+        super::buf::peek_slices(self.bufs.iter().map(Bytes::as_slice), offset, dst)
+    }
+
+    fn copy_to_bytes(&mut self, len: usize) -> Bytes {
+        assert!(len <= self.remaining, "`len` is larger than the remaining buf");
+
+        match self.bufs.front_mut() {
+            // zero copy if the range sits in the first segment
+            Some(front) if front.len() >= len => {
+                let bytes = front.split_to(len);
+                if front.is_empty() {
+                    self.bufs.pop_front();
+                }
+                self.remaining -= len;
+                bytes
+            }
+            _ => {
+                let mut bufm = BytesMut::with_capacity(len);
+                bufm.put(self.take(len));
+                bufm.freeze()
+            }
+        }
+    }
+}
+
+impl From<Bytes> for BufList {
+    #[inline]
+    fn from(value: Bytes) -> Self {
+        let mut list = BufList::new();
+        list.push_back(value);
+        list
+    }
+}
+
+impl Extend<Bytes> for BufList {
+    #[inline]
+    fn extend<T: IntoIterator<Item = Bytes>>(&mut self, iter: T) {
+        for bytes in iter {
+            self.push_back(bytes);
+        }
+    }
+}
+
+impl FromIterator<Bytes> for BufList {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = Bytes>>(iter: T) -> Self {
+        let mut list = BufList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl IntoIterator for BufList {
+    type Item = Bytes;
+    type IntoIter = vec_deque::IntoIter<Bytes>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.bufs.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a BufList {
+    type Item = &'a Bytes;
+    type IntoIter = vec_deque::Iter<'a, Bytes>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.bufs.iter()
+    }
+}
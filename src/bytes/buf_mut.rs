@@ -1,7 +1,8 @@
 use core::mem::{self, MaybeUninit};
 use core::ptr;
 
-use crate::bytes::{Chain, UninitSlice};
+use crate::bytes::varint;
+use crate::bytes::{Chain, Limit, UninitSlice, Writer};
 
 macro_rules! fn_put_int {
     ($ty:ident, $m:ident, $f:ident, $doc:literal) => {
@@ -27,6 +28,7 @@ macro_rules! fn_put_int {
             u32, put_u32, i32, put_i32,
             u64, put_u64, i64, put_i64,
             u128, put_u128, i128, put_i128,
+            f32, put_f32, f64, put_f64,
         );
         fn_put_int!(
             to_le_bytes, "little endian",
@@ -34,6 +36,7 @@ macro_rules! fn_put_int {
             u32, put_u32_le, i32, put_i32_le,
             u64, put_u64_le, i64, put_i64_le,
             u128, put_u128_le, i128, put_i128_le,
+            f32, put_f32_le, f64, put_f64_le,
         );
         fn_put_int!(
             to_ne_bytes, "native endian",
@@ -41,9 +44,43 @@ macro_rules! fn_put_int {
             u32, put_u32_ne, i32, put_i32_ne,
             u64, put_u64_ne, i64, put_i64_ne,
             u128, put_u128_ne, i128, put_i128_ne,
+            f32, put_f32_ne, f64, put_f64_ne,
         );
     };
 }
+macro_rules! fn_put_uint {
+    ($uint:ident, $int:ident, $f:ident, $be:expr, $doc:literal) => {
+        #[doc = concat!("Put the lower `nbytes` bytes of unsigned integer in ", $doc, ".")]
+        ///
+        /// # Panics
+        ///
+        /// Panics if `nbytes > 8`, or `self` does not have enough capacity to contain `nbytes`.
+        #[inline]
+        fn $uint(&mut self, n: u64, nbytes: usize) {
+            let range = crate::bytes::buf::uint_range(nbytes, $be);
+            self.put_slice(&n.$f()[range]);
+        }
+
+        #[doc = concat!("Put the lower `nbytes` bytes of signed integer in ", $doc, ".")]
+        ///
+        /// # Panics
+        ///
+        /// Panics if `nbytes > 8`, or `self` does not have enough capacity to contain `nbytes`.
+        #[inline]
+        fn $int(&mut self, n: i64, nbytes: usize) {
+            let range = crate::bytes::buf::uint_range(nbytes, $be);
+            self.put_slice(&n.$f()[range]);
+        }
+    };
+    () => {
+        fn_put_uint!(put_uint, put_int, to_be_bytes, true, "big endian");
+        fn_put_uint!(put_uint_le, put_int_le, to_le_bytes, false, "little endian");
+        fn_put_uint!(
+            put_uint_ne, put_int_ne, to_ne_bytes, cfg!(target_endian = "big"), "native endian"
+        );
+    };
+}
+
 /// A trait for values that provide sequential write access to bytes.
 ///
 /// Write bytes to a buffer
@@ -107,6 +144,43 @@ pub trait BufMut {
     /// A call with `cnt == 0` should never panic and be a no-op.
     unsafe fn advance_mut(&mut self, cnt: usize);
 
+    /// Fills `dst` with potentially multiple mutable slices starting at `self`'s current position.
+    ///
+    /// If the `BufMut` is backed by disjoint slices of bytes, `chunks_vectored_mut` enables
+    /// fetching more than one slice at once, such that it can be used with [`readv`]. The sum of
+    /// the lengths of all the slices written to `dst` will be less than or equal to
+    /// `BufMut::remaining_mut()`.
+    ///
+    /// The slices are in the order they are written, writing `cnt` bytes across the slices
+    /// followed by `advance_mut(cnt)` is equivalent to writing them sequentially. The return value
+    /// is the number of slices written to `dst`. If `BufMut::remaining_mut()` is non-zero, then
+    /// this writes at least one non-empty slice to `dst`.
+    ///
+    /// This is a lower level function. Most operations are done with other functions.
+    ///
+    /// # Implementation notes
+    ///
+    /// This function should never panic. Implementations should take care to properly handle
+    /// being called with `dst` being a zero length slice.
+    ///
+    /// Following slices must only be written if the previous slices covers all the remaining
+    /// capacity of the underlying segment, otherwise writing across the slices would skip bytes.
+    ///
+    /// [`readv`]: http://man7.org/linux/man-pages/man2/readv.2.html
+    #[inline]
+    fn chunks_vectored_mut<'a>(&'a mut self, dst: &mut [&'a mut UninitSlice]) -> usize {
+        if dst.is_empty() {
+            return 0;
+        }
+
+        if self.has_remaining_mut() {
+            dst[0] = self.chunk_mut();
+            1
+        } else {
+            0
+        }
+    }
+
     /// Returns true if there is space in `self` for more bytes.
     ///
     /// This is equivalent to `self.remaining_mut() != 0`.
@@ -181,6 +255,35 @@ pub trait BufMut {
         Chain::new(self, next)
     }
 
+    /// Creates an adaptor which will write at most `limit` bytes to `self`.
+    #[inline]
+    fn limit(self, limit: usize) -> Limit<Self>
+    where
+        Self: Sized,
+    {
+        Limit::new(self, limit)
+    }
+
+    /// Creates an adaptor which implements [`std::io::Write`] for `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    /// use tcio::bytes::BufMut;
+    ///
+    /// let mut writer = vec![].limit(8).writer();
+    /// assert_eq!(writer.write(b"Hello World!").unwrap(), 8);
+    /// assert_eq!(writer.into_inner().into_inner(), b"Hello Wo");
+    /// ```
+    #[inline]
+    fn writer(self) -> Writer<Self>
+    where
+        Self: Sized,
+    {
+        Writer::new(self)
+    }
+
     /// Put `u8`.
     ///
     /// `self` is `advanced_mut` by 1.
@@ -218,6 +321,77 @@ pub trait BufMut {
     }
 
     fn_put_int!();
+
+    fn_put_uint!();
+
+    /// Put `count` bytes of `val`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` does not have enough capacity to contain `count` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tcio::bytes::BufMut;
+    ///
+    /// let mut buf = vec![];
+    /// buf.put_bytes(b'a', 4);
+    /// assert_eq!(buf, b"aaaa");
+    /// ```
+    #[inline]
+    fn put_bytes(&mut self, val: u8, mut count: usize) {
+        if count > self.remaining_mut() {
+            remaining_mut_fail(count, self.remaining_mut());
+        }
+
+        while count != 0 {
+            let dst = self.chunk_mut();
+            let cnt = usize::min(count, dst.len());
+
+            // SAFETY: `dst` is valid for `cnt` bytes write
+            unsafe { ptr::write_bytes(dst.as_mut_ptr(), val, cnt) };
+            count -= cnt;
+
+            // SAFETY: We just initialized `cnt` bytes in `self`.
+            unsafe { self.advance_mut(cnt) };
+        }
+    }
+
+    /// Put unsigned LEB128 variable-length integer.
+    ///
+    /// The number of bytes written is [`varint_len(n)`][crate::bytes::varint_len].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` does not have enough capacity to contain the encoded integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tcio::bytes::BufMut;
+    ///
+    /// let mut buf = vec![];
+    /// buf.put_uvarint(300);
+    /// buf.put_ivarint(-2);
+    /// assert_eq!(buf, b"\xac\x02\x03");
+    /// ```
+    #[inline]
+    fn put_uvarint(&mut self, n: u64) {
+        let mut buf = [0u8; varint::MAX_LEN];
+        let len = varint::encode(n, &mut buf);
+        self.put_slice(&buf[..len]);
+    }
+
+    /// Put zigzag encoded signed LEB128 variable-length integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` does not have enough capacity to contain the encoded integer.
+    #[inline]
+    fn put_ivarint(&mut self, n: i64) {
+        self.put_uvarint(varint::zigzag_encode(n));
+    }
 }
 
 /// This macro make sure to forward methods which may be overriden by the implementor.
@@ -229,6 +403,8 @@ macro_rules! delegate_blanket_impl {
         #[inline] fn chunk_mut(&mut self) -> &mut UninitSlice { T::chunk_mut(self) }
         #[inline] unsafe fn advance_mut(&mut self, cnt: usize)
             { unsafe { T::advance_mut(self, cnt) } }
+        #[inline] fn chunks_vectored_mut<'a>(&'a mut self, dst: &mut [&'a mut UninitSlice])
+            -> usize { T::chunks_vectored_mut(self, dst) }
         #[inline] fn has_remaining_mut(&self) -> bool { T::has_remaining_mut(self) }
         #[inline] fn put_slice(&mut self, src: &[u8]) { T::put_slice(self, src) } };
 }
@@ -49,6 +49,40 @@ impl Bytes {
         Self::from_vec(data.to_vec())
     }
 
+    /// Create new [`Bytes`] from a buffer owned by `owner`, without copying.
+    ///
+    /// The `owner` is kept alive until the last handle of the buffer is dropped. The buffer is
+    /// the slice returned by a single call of `owner.as_ref()`.
+    ///
+    /// Note that unless the `owner` is a `Vec<u8>`, [`Bytes::into_vec`] and [`Bytes::into_mut`]
+    /// always copies the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use tcio::bytes::Bytes;
+    ///
+    /// let shared: Arc<[u8]> = Arc::from(&b"Hello World!"[..]);
+    /// let mut bytes = Bytes::from_owner(shared.clone());
+    ///
+    /// let hello = bytes.split_to(5);
+    /// assert_eq!(hello.as_ptr(), shared.as_ptr());
+    /// assert_eq!(hello, b"Hello"[..]);
+    /// ```
+    pub fn from_owner<T>(owner: T) -> Self
+    where
+        T: AsRef<[u8]> + Send + Sync + 'static,
+    {
+        let (shared, len) = shared::promote_with_owner(owner);
+        Self {
+            // SAFETY: `shared` is a valid pointer, just allocated
+            ptr: unsafe { NonNull::new_unchecked((*shared).as_ptr()) },
+            len,
+            data: AtomicPtr::new(shared),
+        }
+    }
+
     pub(crate) fn from_vec(mut vec: Vec<u8>) -> Self {
         if vec.is_empty() {
             return Self::new();
@@ -141,7 +175,6 @@ impl Bytes {
         }
     }
 
-    #[cfg(test)]
     #[doc(hidden)]
     pub(crate) fn data(&self) -> &AtomicPtr<Shared> {
         &self.data
@@ -555,6 +588,35 @@ impl Bytes {
         clone.len = at;
         Some(clone)
     }
+
+    /// Absorbs a `Bytes` that was previously split off.
+    ///
+    /// If the two `Bytes` were previously contiguous and share the same allocation, this is an
+    /// `O(1)` operation that just decrease a reference count, sets few indices and returns
+    /// [`Ok`].
+    ///
+    /// Otherwise, it returns [`Err`] containing the same given `Bytes`.
+    pub(crate) fn try_unsplit(&mut self, other: Bytes) -> Result<(), Bytes> {
+        if other.is_empty() {
+            return Ok(());
+        }
+
+        let data = *self.data.get_mut();
+        // SAFETY: `self.ptr.add(self.len)` is always valid
+        let ptr = unsafe { self.ptr.add(self.len) };
+
+        // static `Bytes` is excluded, two adjacent static slices may be different allocation
+        if ptr == other.ptr
+            && !data.is_null()
+            && shared::is_promoted(data)
+            && ptr::eq(data, other.data.load(Ordering::Relaxed))
+        {
+            self.len += other.len;
+            Ok(())
+        } else {
+            Err(other)
+        }
+    }
 }
 
 // ===== Atomic Operations =====
@@ -562,6 +624,8 @@ impl Bytes {
 impl Bytes {
     /// Returns `true` if `Bytes` is the only handle in a shared buffer.
     ///
+    /// Returns `false` if there is any [`WeakBytes`] handle of the buffer.
+    ///
     /// `Bytes` constructed from [`Bytes::from_static`] will always returns `false`.
     #[inline]
     pub fn is_unique(&self) -> bool {
@@ -647,21 +711,25 @@ impl Bytes {
             return;
         }
 
-        match shared::into_unpromoted(shared) {
-            Ok(offset) => {
+        match shared::to_unpromoted(shared) {
+            Some(offset) => {
                 let _ = self.build_unpromoted_vec(offset);
             }
-            Err(shared) => {
-                shared::release(shared);
+            None => {
+                // SAFETY: `self` is released
+                unsafe { shared::release(shared) };
             }
         }
     }
 
     /// Converts a [`Bytes`] into a byte vector.
     ///
-    /// If [`Bytes::is_unique`] returns `true`, the buffer is consumed and returned.
+    /// If `self` is the only strong handle of the buffer, the buffer is consumed and returned.
+    /// Unlike [`Bytes::is_unique`], [`WeakBytes`] handles does not prevent this, they can no
+    /// longer be upgraded afterwards.
     ///
-    /// Otherwise, the buffer is copied to new allocation.
+    /// Otherwise, or if the `Bytes` is created via [`Bytes::from_owner`] with an owner other than
+    /// `Vec<u8>`, the buffer is copied to new allocation.
     pub fn into_vec(self) -> Vec<u8> {
         let mut bytes = ManuallyDrop::new(self);
         let shared = *bytes.data.get_mut();
@@ -672,18 +740,20 @@ impl Bytes {
 
         let ptr = bytes.ptr.as_ptr();
 
-        let (advanced, mut vec) = match shared::into_unpromoted(shared) {
+        let (advanced, mut vec) = match shared::as_unpromoted(shared) {
             Ok(offset) => (offset, bytes.build_unpromoted_vec(offset)),
-            Err(shared) => {
-                let base_ptr = shared.as_ptr();
-                let cap = shared.capacity();
+            Err(shared_ref) => {
+                let base_ptr = shared_ref.as_ptr();
+                let cap = shared_ref.capacity();
                 unsafe {
                     match shared::release_into_vec(shared, cap) {
                         Some(vec) => (ptr.offset_from_unsigned(base_ptr), vec),
                         None => {
                             // skip handling the `advance` below if we can directly copy
-                            // the correct range
-                            return bytes.as_slice().to_vec();
+                            // the correct range, *before* release which may drop the buffer
+                            let vec = bytes.as_slice().to_vec();
+                            shared::release(shared);
+                            return vec;
                         }
                     }
                 }
@@ -711,9 +781,12 @@ impl Bytes {
 
     /// Converts a [`Bytes`] into a [`BytesMut`].
     ///
-    /// If [`Bytes::is_unique`] returns `true`, the buffer is consumed and returned.
+    /// If `self` is the only strong handle of the buffer, the buffer is consumed and returned.
+    /// Unlike [`Bytes::is_unique`], [`WeakBytes`] handles does not prevent this, they can no
+    /// longer be upgraded afterwards.
     ///
-    /// Otherwise, the buffer is copied to new allocation.
+    /// Otherwise, or if the `Bytes` is created via [`Bytes::from_owner`] with an owner other than
+    /// `Vec<u8>`, the buffer is copied to new allocation.
     pub fn into_mut(self) -> BytesMut {
         let mut bytes = ManuallyDrop::new(self);
         let shared = *bytes.data.get_mut();
@@ -724,7 +797,7 @@ impl Bytes {
 
         let ptr = bytes.ptr.as_ptr();
 
-        match shared::into_unpromoted(shared) {
+        match shared::as_unpromoted(shared) {
             Ok(offset) => {
                 let mut bufm = BytesMut::from_vec(bytes.build_unpromoted_vec(offset));
                 unsafe {
@@ -734,9 +807,9 @@ impl Bytes {
                 }
                 bufm
             }
-            Err(shared) => {
-                let base_ptr = shared.as_ptr();
-                let cap = shared.capacity();
+            Err(shared_ref) => {
+                let base_ptr = shared_ref.as_ptr();
+                let cap = shared_ref.capacity();
                 match unsafe { shared::release_into_vec(shared, cap) } {
                     Some(vec) => {
                         let mut bufm = BytesMut::from_vec(vec);
@@ -748,7 +821,13 @@ impl Bytes {
                         }
                         bufm
                     }
-                    None => BytesMut::from_vec(bytes.as_slice().to_vec()),
+                    None => {
+                        // copy *before* release, which may drop the buffer
+                        let bufm = BytesMut::from_vec(bytes.as_slice().to_vec());
+                        // SAFETY: `bytes` is `ManuallyDrop`, so the handle is only released here
+                        unsafe { shared::release(shared) };
+                        bufm
+                    }
                 }
             }
         }
@@ -800,7 +879,7 @@ fn promote_ref(me: &Bytes, offset: usize, shared: *mut Shared) -> Bytes {
 
             unsafe {
                 // release the heap that failed the promotion
-                shared::release(Box::from_raw(new_shared));
+                shared::release(new_shared);
 
                 // increase the shared reference
                 shared::increment(&*promoted_shared);
@@ -815,6 +894,155 @@ fn promote_ref(me: &Bytes, offset: usize, shared: *mut Shared) -> Bytes {
     }
 }
 
+// ===== Weak =====
+
+impl Bytes {
+    /// Creates a [`WeakBytes`] handle to the buffer.
+    ///
+    /// The weak handle does not keep the buffer alive, but [`WeakBytes::upgrade`] returns the
+    /// buffer as long as any other handle of the same allocation is alive, including the
+    /// [`BytesMut`] it was split from.
+    ///
+    /// [`Bytes::is_unique`] returns `false` while any weak handle exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tcio::bytes::Bytes;
+    ///
+    /// let bytes = Bytes::copy_from_slice(b"Hello World!");
+    /// let weak = bytes.downgrade();
+    ///
+    /// assert_eq!(weak.upgrade().unwrap(), b"Hello World!"[..]);
+    ///
+    /// drop(bytes);
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    pub fn downgrade(&self) -> WeakBytes {
+        let shared = self.data.load(Ordering::Acquire);
+
+        let data = if shared.is_null() {
+            shared
+        } else {
+            match shared::as_unpromoted(shared) {
+                Ok(offset) => promote_weak(self, offset, shared),
+                Err(shared_ref) => {
+                    shared::downgrade(shared_ref);
+                    shared
+                }
+            }
+        };
+
+        WeakBytes {
+            ptr: self.ptr,
+            len: self.len,
+            data,
+        }
+    }
+}
+
+// weak handle requires promotion, the same as `promote_ref`, but only the weak count is
+// incremented
+#[cold]
+fn promote_weak(me: &Bytes, offset: usize, shared: *mut Shared) -> *mut Shared {
+    let vec = me.build_unpromoted_vec(offset);
+    let new_shared = shared::promote_with_vec(vec, 1);
+    // SAFETY: `new_shared` is just allocated
+    shared::downgrade(unsafe { &*new_shared });
+
+    match me.data.compare_exchange(
+        shared,
+        new_shared,
+        Ordering::AcqRel,
+        Ordering::Acquire,
+    ) {
+        Ok(_) => new_shared,
+        Err(promoted_shared) => {
+            // concurrent promotion happens during heap allocation
+            debug_assert!(shared::is_promoted(promoted_shared));
+
+            unsafe {
+                // deallocate only the `Shared` that failed the promotion, the buffer is owned
+                // by `promoted_shared`
+                drop(Box::from_raw(new_shared));
+
+                shared::downgrade(&*promoted_shared);
+            }
+
+            promoted_shared
+        }
+    }
+}
+
+/// A weak handle of [`Bytes`] buffer, created by [`Bytes::downgrade`].
+///
+/// It does not keep the buffer alive, use [`WeakBytes::upgrade`] to access the buffer.
+pub struct WeakBytes {
+    ptr: NonNull<u8>,
+    len: usize,
+    /// 1. null, static value
+    /// 2. *mut Shared
+    data: *mut Shared,
+}
+
+unsafe impl Send for WeakBytes {}
+unsafe impl Sync for WeakBytes {}
+
+impl WeakBytes {
+    /// Attempt to upgrade into [`Bytes`].
+    ///
+    /// Returns `None` if all the handles of the buffer have been dropped.
+    ///
+    /// Weak handle of a `Bytes` constructed from [`Bytes::from_static`] always succeed.
+    pub fn upgrade(&self) -> Option<Bytes> {
+        if !self.data.is_null() {
+            // SAFETY: weak handle keeps `Shared` alive
+            if !shared::upgrade(unsafe { &*self.data }) {
+                return None;
+            }
+        }
+
+        Some(Bytes {
+            ptr: self.ptr,
+            len: self.len,
+            data: AtomicPtr::new(self.data),
+        })
+    }
+}
+
+impl Clone for WeakBytes {
+    #[inline]
+    fn clone(&self) -> Self {
+        if !self.data.is_null() {
+            // SAFETY: weak handle keeps `Shared` alive
+            shared::downgrade(unsafe { &*self.data });
+        }
+
+        Self {
+            ptr: self.ptr,
+            len: self.len,
+            data: self.data,
+        }
+    }
+}
+
+impl Drop for WeakBytes {
+    #[inline]
+    fn drop(&mut self) {
+        if !self.data.is_null() {
+            // SAFETY: weak handle keeps `Shared` alive
+            unsafe { shared::release_weak(self.data) };
+        }
+    }
+}
+
+impl std::fmt::Debug for WeakBytes {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("(WeakBytes)")
+    }
+}
+
 // ===== std traits =====
 
 impl Drop for Bytes {
@@ -2,7 +2,9 @@ use std::cmp;
 use std::mem::{ManuallyDrop, MaybeUninit};
 use std::ptr::{self, NonNull};
 use std::slice;
+use std::sync::Arc;
 
+use crate::bytes::pool::PoolInner;
 use crate::bytes::shared::{self, Shared};
 use crate::bytes::{Buf, Bytes, UninitSlice};
 
@@ -126,6 +128,20 @@ impl BytesMut {
         }
     }
 
+    /// Create new empty [`BytesMut`] which give back the buffer to the `pool` when the last
+    /// handle is dropped.
+    pub(crate) fn from_pooled(mut vec: Vec<u8>, pool: Arc<PoolInner>) -> BytesMut {
+        vec.clear();
+        let cap = vec.capacity();
+        let ptr = unsafe { NonNull::new_unchecked(vec.as_mut_ptr()) };
+        BytesMut {
+            ptr,
+            len: 0,
+            cap,
+            data: shared::promote_with_pool(vec, pool),
+        }
+    }
+
     /// Returns the number of bytes in the `BytesMut`.
     #[inline]
     pub const fn len(&self) -> usize {
@@ -256,7 +272,7 @@ impl BytesMut {
         let ptr = self.ptr.as_ptr();
         let len = self.len;
 
-        match shared::as_unpromoted_mut(self.data) {
+        match shared::as_unpromoted(self.data) {
             Ok(offset) => {
                 let remaining = offset + (self.cap - self.len);
 
@@ -359,7 +375,7 @@ impl BytesMut {
 
                     // release the shared buffer *after* copy
                     // let old_shared = ptr::read(shared);
-                    shared::release(Box::from_raw(shared));
+                    shared::release(self.data);
 
                     self.ptr = NonNull::new_unchecked(new_ptr);
                     self.cap = new_vec.capacity();
@@ -582,6 +598,34 @@ impl BytesMut {
         }
     }
 
+    /// Splits `BytesMut` after the first occurrence of `delimiter`.
+    ///
+    /// Afterwards `self` contains elements after the delimiter, and the returned `BytesMut`
+    /// contains elements up to and including the delimiter.
+    ///
+    /// Returns `None` if the delimiter is not found, `self` is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delimiter` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tcio::bytes::BytesMut;
+    /// let mut bytes = BytesMut::copy_from_slice(b"PING\r\nPONG");
+    /// let frame = bytes.split_to_delimiter(b"\r\n").unwrap();
+    /// assert_eq!(&frame, &b"PING\r\n"[..]);
+    /// assert_eq!(&bytes, &b"PONG"[..]);
+    /// assert!(bytes.split_to_delimiter(b"\r\n").is_none());
+    /// ```
+    #[inline]
+    pub fn split_to_delimiter(&mut self, delimiter: &[u8]) -> Option<BytesMut> {
+        assert!(!delimiter.is_empty(), "`split_to_delimiter` delimiter is empty");
+        let at = crate::bytes::find_subslice(self.as_slice(), delimiter)?;
+        Some(self.split_to(at + delimiter.len()))
+    }
+
     /// Splits `BytesMut` into two at the given index.
     ///
     /// Afterwards `self` contains elements `[0, at)`, and the returned `BytesMut` contains
@@ -804,6 +848,42 @@ impl BytesMut {
             Err(other)
         }
     }
+
+    /// Absorbs a frozen `Bytes` that was previously split off from the front of `self`.
+    ///
+    /// This only succeed if `head` is contiguous with `self` and both are the only handles of the
+    /// shared buffer, such that no one else can observe `head` becoming mutable again.
+    ///
+    /// Otherwise, it returns [`Err`] containing the same given `Bytes`.
+    pub(crate) fn try_unsplit_frozen(&mut self, head: Bytes) -> Result<(), Bytes> {
+        if head.is_empty() {
+            return Ok(());
+        }
+
+        let head_data = head.data().load(std::sync::atomic::Ordering::Relaxed);
+        let head_end = head.as_ptr().wrapping_add(head.len());
+
+        if head_end != self.ptr.as_ptr()
+            || !shared::is_promoted(self.data)
+            || !ptr::eq(head_data, self.data)
+            // SAFETY: `self.data` is promoted
+            || !shared::is_pair(unsafe { &*self.data })
+        {
+            return Err(head);
+        }
+
+        unsafe {
+            // SAFETY: `head` is the leading bytes of `self` in the same allocation
+            self.ptr = NonNull::new_unchecked(head.as_ptr().cast_mut());
+        }
+        self.len += head.len();
+        self.cap += head.len();
+
+        // release the reference of `head`
+        drop(head);
+
+        Ok(())
+    }
 }
 
 // ===== std traits =====
@@ -811,13 +891,14 @@ impl BytesMut {
 impl Drop for BytesMut {
     #[inline]
     fn drop(&mut self) {
-        match shared::into_unpromoted(self.data) {
-            Ok(offset) => {
+        match shared::to_unpromoted(self.data) {
+            Some(offset) => {
                 // SAFETY: to be drop
                 unsafe { drop(self.original_buffer(offset)) };
             },
-            Err(shared) => {
-                shared::release(shared);
+            None => {
+                // SAFETY: to be drop
+                unsafe { shared::release(self.data) };
             },
         }
     }
@@ -1,4 +1,4 @@
-use super::Bytes;
+use super::{ByteStrMut, Bytes};
 
 /// A cheaply cloneable and sliceable str.
 ///
@@ -70,34 +70,26 @@ impl ByteStr {
         Self { bytes: Bytes::from_static(string.as_bytes()) }
     }
 
-    // /// Try to get mutable reference to underlying string.
-    // ///
-    // /// If `self` is not unique for the entire original buffer, callback not called and return `false`.
-    // ///
-    // /// # Examples
-    // ///
-    // /// ```
-    // /// use tcio::ByteStr;
-    // ///
-    // /// let mut text = ByteStr::copy_from_str("Content-Type");
-    // /// assert!(text.try_mut(|e|e.make_ascii_lowercase()));
-    // /// assert_eq!(&text, "content-type");
-    // /// ```
-    // pub fn try_mut<F: FnOnce(&mut str)>(&mut self, f: F) -> bool {
-    //     match Bytes::try_into_mut(std::mem::take(&mut self.bytes)) {
-    //         Ok(mut original) => {
-    //             // SAFETY: invariant bytes is a valid utf8
-    //             let str_mut = unsafe { str::from_utf8_unchecked_mut(&mut original) };
-    //             f(str_mut);
-    //             self.bytes = original.freeze();
-    //             true
-    //         },
-    //         Err(original) => {
-    //             self.bytes = original;
-    //             false
-    //         },
-    //     }
-    // }
+    /// Converts a [`ByteStr`] into a [`ByteStrMut`].
+    ///
+    /// The buffer is reused if [`ByteStr::is_unique`] returns `true`, otherwise it is copied, see
+    /// [`Bytes::into_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tcio::bytes::ByteStr;
+    ///
+    /// let text = ByteStr::copy_from_str("Content-Type");
+    /// let mut text = text.into_mut();
+    /// text.make_ascii_lowercase();
+    /// assert_eq!(text.as_str(), "content-type");
+    /// ```
+    #[inline]
+    pub fn into_mut(self) -> ByteStrMut {
+        // SAFETY: invariant bytes is a valid utf8
+        unsafe { ByteStrMut::from_utf8_unchecked(self.bytes.into_mut()) }
+    }
 
     /// Shortens the buffer, keeping the first `len` bytes and dropping the rest.
     ///
@@ -149,6 +141,145 @@ impl ByteStr {
         Self { bytes: Bytes::slice_ref(&self.bytes, subset.as_bytes()) }
     }
 
+    /// Returns a subslice of `ByteStr` with given byte `range`.
+    ///
+    /// This operation is `O(1)`.
+    ///
+    /// Returns `None` if the range is out of bounds or does not lie on [`char`] boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tcio::bytes::ByteStr;
+    /// let text = ByteStr::from_static("αβγ");
+    /// assert_eq!(text.get_bytestr(2..4).unwrap().as_str(), "β");
+    /// assert!(text.get_bytestr(1..4).is_none());
+    /// assert!(text.get_bytestr(..7).is_none());
+    /// ```
+    pub fn get_bytestr(&self, range: impl std::ops::RangeBounds<usize>) -> Option<Self> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        let sub = self.as_str().get(range)?;
+        Some(self.slice_ref(sub))
+    }
+
+    /// Splits the string into two at the given byte index.
+    ///
+    /// Afterwards `self` contains `[at, len)`, and the returned `ByteStr` contains `[0, at)`.
+    ///
+    /// Returns `None` if `at` is out of bounds or does not lie on a [`char`] boundary.
+    #[inline]
+    pub fn split_to(&mut self, at: usize) -> Option<Self> {
+        if !self.is_char_boundary(at) {
+            return None;
+        }
+        Some(Self { bytes: self.bytes.split_to(at) })
+    }
+
+    /// Splits the string into two at the given byte index.
+    ///
+    /// Afterwards `self` contains `[0, at)`, and the returned `ByteStr` contains `[at, len)`.
+    ///
+    /// Returns `None` if `at` is out of bounds or does not lie on a [`char`] boundary.
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> Option<Self> {
+        if !self.is_char_boundary(at) {
+            return None;
+        }
+        Some(Self { bytes: self.bytes.split_off(at) })
+    }
+
+    /// Splits the string on the first occurrence of `delimiter`.
+    ///
+    /// Returns the string before and after the delimiter, or `None` if the delimiter is not
+    /// found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tcio::bytes::ByteStr;
+    /// let header = ByteStr::copy_from_str("content-type: text/plain");
+    /// let (name, value) = header.split_once_bytestr(": ").unwrap();
+    /// assert_eq!(name.as_str(), "content-type");
+    /// assert_eq!(value.as_str(), "text/plain");
+    /// ```
+    pub fn split_once_bytestr(&self, delimiter: &str) -> Option<(Self, Self)> {
+        let (left, right) = self.as_str().split_once(delimiter)?;
+        Some((self.slice_ref(left), self.slice_ref(right)))
+    }
+
+    /// Splits the string on the last occurrence of `delimiter`.
+    ///
+    /// Returns the string before and after the delimiter, or `None` if the delimiter is not
+    /// found.
+    pub fn rsplit_once_bytestr(&self, delimiter: &str) -> Option<(Self, Self)> {
+        let (left, right) = self.as_str().rsplit_once(delimiter)?;
+        Some((self.slice_ref(left), self.slice_ref(right)))
+    }
+
+    /// Returns an iterator over substrings separated by `delimiter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tcio::bytes::ByteStr;
+    /// let list = ByteStr::copy_from_str("gzip, deflate, br");
+    /// let mut iter = list.split_bytestr(", ");
+    /// assert_eq!(iter.next().unwrap().as_str(), "gzip");
+    /// assert_eq!(iter.next().unwrap().as_str(), "deflate");
+    /// assert_eq!(iter.next().unwrap().as_str(), "br");
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn split_bytestr<'a>(&'a self, delimiter: &'a str) -> ByteStrSplit<'a> {
+        ByteStrSplit {
+            bytestr: self,
+            inner: self.as_str().split(delimiter),
+        }
+    }
+
+    /// Returns an iterator over the lines of the string.
+    ///
+    /// Lines are split at `\n` or `\r\n`, the line terminator is not included.
+    #[inline]
+    pub fn lines_bytestr(&self) -> ByteStrLines<'_> {
+        ByteStrLines {
+            bytestr: self,
+            inner: self.as_str().lines(),
+        }
+    }
+
+    /// Returns the string with leading and trailing whitespace removed.
+    #[inline]
+    pub fn trim_bytestr(&self) -> Self {
+        self.slice_ref(self.as_str().trim())
+    }
+
+    /// Returns the string with leading whitespace removed.
+    #[inline]
+    pub fn trim_start_bytestr(&self) -> Self {
+        self.slice_ref(self.as_str().trim_start())
+    }
+
+    /// Returns the string with trailing whitespace removed.
+    #[inline]
+    pub fn trim_end_bytestr(&self) -> Self {
+        self.slice_ref(self.as_str().trim_end())
+    }
+
+    /// Returns the string with the `prefix` removed, or `None` if the string does not start with
+    /// `prefix`.
+    #[inline]
+    pub fn strip_prefix_bytestr(&self, prefix: &str) -> Option<Self> {
+        Some(self.slice_ref(self.as_str().strip_prefix(prefix)?))
+    }
+
+    /// Returns the string with the `suffix` removed, or `None` if the string does not end with
+    /// `suffix`.
+    #[inline]
+    pub fn strip_suffix_bytestr(&self, suffix: &str) -> Option<Self> {
+        Some(self.slice_ref(self.as_str().strip_suffix(suffix)?))
+    }
+
     /// Convert [`ByteStr`] into [`String`].
     ///
     /// The bytes move/copy behavior is depends on [`Into<Vec>`] implementation of [`Bytes`].
@@ -250,6 +381,55 @@ crate::macros::partial_eq! {
     fn eq(self, other: Self) { str::eq(self, other.as_str()) }
 }
 
+// ===== Iterators =====
+
+/// An iterator over substrings of [`ByteStr`] separated by a delimiter.
+///
+/// This struct is created by [`ByteStr::split_bytestr`].
+#[derive(Debug, Clone)]
+pub struct ByteStrSplit<'a> {
+    bytestr: &'a ByteStr,
+    inner: std::str::Split<'a, &'a str>,
+}
+
+impl Iterator for ByteStrSplit<'_> {
+    type Item = ByteStr;
+
+    #[inline]
+    fn next(&mut self) -> Option<ByteStr> {
+        self.inner.next().map(|sub| self.bytestr.slice_ref(sub))
+    }
+}
+
+impl std::iter::FusedIterator for ByteStrSplit<'_> {}
+
+/// An iterator over the lines of [`ByteStr`].
+///
+/// This struct is created by [`ByteStr::lines_bytestr`].
+#[derive(Debug, Clone)]
+pub struct ByteStrLines<'a> {
+    bytestr: &'a ByteStr,
+    inner: std::str::Lines<'a>,
+}
+
+impl Iterator for ByteStrLines<'_> {
+    type Item = ByteStr;
+
+    #[inline]
+    fn next(&mut self) -> Option<ByteStr> {
+        self.inner.next().map(|sub| self.bytestr.slice_ref(sub))
+    }
+}
+
+impl DoubleEndedIterator for ByteStrLines<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<ByteStr> {
+        self.inner.next_back().map(|sub| self.bytestr.slice_ref(sub))
+    }
+}
+
+impl std::iter::FusedIterator for ByteStrLines<'_> {}
+
 // ===== Error =====
 
 /// A possible error value when converting a `String` from a UTF-8 byte vector.
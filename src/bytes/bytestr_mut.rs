@@ -0,0 +1,295 @@
+use std::fmt;
+
+use super::{ByteStr, BytesMut};
+
+/// A growable UTF-8 string backed by [`BytesMut`].
+///
+/// A mutable [`String`] which can be split and [`freeze`]d into [`ByteStr`] without copying.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Write;
+/// use tcio::bytes::ByteStrMut;
+///
+/// let mut string = ByteStrMut::with_capacity(64);
+/// write!(string, "content-length: {}\r\n", 12).unwrap();
+/// string.push_str("host: example.com\r\n");
+///
+/// let content_length = string.split_to(20).freeze();
+/// let host = string.freeze();
+///
+/// assert_eq!(content_length.as_str(), "content-length: 12\r\n");
+/// assert_eq!(host.as_str(), "host: example.com\r\n");
+/// ```
+///
+/// [`freeze`]: ByteStrMut::freeze
+pub struct ByteStrMut {
+    /// INVARIANT: bytes is a valid utf8
+    bytes: BytesMut,
+}
+
+impl ByteStrMut {
+    /// Creates new empty [`ByteStrMut`].
+    ///
+    /// This function does not allocate.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { bytes: BytesMut::new() }
+    }
+
+    /// Creates new empty [`ByteStrMut`] with at least specified capacity.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { bytes: BytesMut::with_capacity(capacity) }
+    }
+
+    /// Creates [`ByteStrMut`] instance from str slice, by copying it.
+    #[inline]
+    pub fn copy_from_str(string: &str) -> Self {
+        Self { bytes: BytesMut::copy_from_slice(string.as_bytes()) }
+    }
+
+    /// Converts a [`BytesMut`] to a [`ByteStrMut`] without checking that the string contains
+    /// valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The bytes passed in must be valid UTF-8.
+    #[inline]
+    pub unsafe fn from_utf8_unchecked(bytes: BytesMut) -> Self {
+        debug_assert!(str::from_utf8(&bytes).is_ok(), "`from_utf8_unchecked` receive non-UTF8");
+        Self { bytes }
+    }
+
+    /// Returns the length of the string in bytes.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if the string has a length of zero.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the bytes that the string can hold without reallocating.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.bytes.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more bytes to be inserted.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.bytes.reserve(additional);
+    }
+
+    /// Extracts a string slice containing the entire `ByteStrMut`.
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: invariant bytes is a valid utf8
+        unsafe { str::from_utf8_unchecked(self.bytes.as_slice()) }
+    }
+
+    /// Extracts a mutable string slice containing the entire `ByteStrMut`.
+    #[inline]
+    pub const fn as_mut_str(&mut self) -> &mut str {
+        // SAFETY: invariant bytes is a valid utf8, and `str` API preserve it
+        unsafe { str::from_utf8_unchecked_mut(self.bytes.as_mut_slice()) }
+    }
+
+    /// Appends the given [`char`] to the end of the string.
+    #[inline]
+    pub fn push(&mut self, ch: char) {
+        self.push_str(ch.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Appends the given string slice to the end of the string.
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        self.bytes.extend_from_slice(string.as_bytes());
+    }
+
+    /// Shortens the string, keeping the first `new_len` bytes and dropping the rest.
+    ///
+    /// If `new_len` is greater or equal to the string length, this has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` does not lie on a [`char`] boundary.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len <= self.len() {
+            assert!(self.as_str().is_char_boundary(new_len), "truncate not on char boundary");
+            self.bytes.truncate(new_len);
+        }
+    }
+
+    /// Clears the string, removing all data.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.bytes.clear();
+    }
+
+    /// Splits the string into two at the given byte index.
+    ///
+    /// Afterwards `self` contains `[at, len)`, and the returned `ByteStrMut` contains `[0, at)`.
+    ///
+    /// This is an `O(1)` operation that just increases the reference count and sets a few indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()` or `at` does not lie on a [`char`] boundary.
+    #[inline]
+    pub fn split_to(&mut self, at: usize) -> ByteStrMut {
+        assert!(self.as_str().is_char_boundary(at), "split_to not on char boundary");
+        Self { bytes: self.bytes.split_to(at) }
+    }
+
+    /// Splits the string into two at the given byte index.
+    ///
+    /// Afterwards `self` contains `[0, at)`, and the returned `ByteStrMut` contains `[at, len)`.
+    ///
+    /// This is an `O(1)` operation that just increases the reference count and sets a few indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()` or `at` does not lie on a [`char`] boundary.
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> ByteStrMut {
+        assert!(self.as_str().is_char_boundary(at), "split_off not on char boundary");
+        Self { bytes: self.bytes.split_off(at) }
+    }
+
+    /// Converts `self` into an immutable [`ByteStr`], sharing the allocation.
+    #[inline]
+    pub fn freeze(self) -> ByteStr {
+        // SAFETY: invariant bytes is a valid utf8
+        unsafe { ByteStr::from_utf8_unchecked(self.bytes.freeze()) }
+    }
+
+    /// Converts a [`ByteStrMut`] into a [`BytesMut`].
+    #[inline]
+    pub fn into_bytes_mut(self) -> BytesMut {
+        self.bytes
+    }
+}
+
+// ===== Constructor =====
+
+crate::macros::from! {
+    impl ByteStrMut;
+    fn from(value: &str) { Self::copy_from_str(value) }
+    fn from(value: String) { Self { bytes: BytesMut::from(value.into_bytes()) } }
+    fn from(value: ByteStr) { value.into_mut() }
+}
+
+impl Default for ByteStrMut {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ===== Destructor =====
+
+impl From<ByteStrMut> for ByteStr {
+    #[inline]
+    fn from(value: ByteStrMut) -> Self {
+        value.freeze()
+    }
+}
+
+impl From<ByteStrMut> for BytesMut {
+    #[inline]
+    fn from(value: ByteStrMut) -> Self {
+        value.bytes
+    }
+}
+
+// ===== References =====
+
+impl AsRef<[u8]> for ByteStrMut {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl AsRef<str> for ByteStrMut {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::ops::Deref for ByteStrMut {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::ops::DerefMut for ByteStrMut {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl fmt::Debug for ByteStrMut {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Display for ByteStrMut {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Write for ByteStrMut {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c);
+        Ok(())
+    }
+}
+
+impl<'a> Extend<&'a str> for ByteStrMut {
+    #[inline]
+    fn extend<T: IntoIterator<Item = &'a str>>(&mut self, iter: T) {
+        for string in iter {
+            self.push_str(string);
+        }
+    }
+}
+
+impl Extend<char> for ByteStrMut {
+    #[inline]
+    fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
+        for ch in iter {
+            self.push(ch);
+        }
+    }
+}
+
+impl Eq for ByteStrMut { }
+
+crate::macros::partial_eq! {
+    impl ByteStrMut;
+    fn eq(self, other: str) { str::eq(self, other) }
+    fn eq(self, other: String) { str::eq(self, other) }
+    fn eq(self, other: Self) { str::eq(self, other.as_str()) }
+}
@@ -54,6 +54,19 @@ impl<T: Buf, U: Buf> Buf for Chain<T, U> {
         cnt
     }
 
+    fn peek_at(&self, offset: usize, dst: &mut [u8]) -> bool {
+        if offset.checked_add(dst.len()).is_none_or(|end| end > self.remaining()) {
+            return false;
+        }
+        let a_rem = self.a.remaining();
+        if let Some(a_offset) = a_rem.checked_sub(offset) {
+            let (a_dst, b_dst) = dst.split_at_mut(a_offset.min(dst.len()));
+            self.a.peek_at(offset, a_dst) && self.b.peek_at(0, b_dst)
+        } else {
+            self.b.peek_at(offset - a_rem, dst)
+        }
+    }
+
     fn copy_to_bytes(&mut self, len: usize) -> super::Bytes {
         let a_rem = self.a.remaining();
         if a_rem >= len {
@@ -86,6 +99,18 @@ impl<T: BufMut, U: BufMut> BufMut for Chain<T, U> {
         }
     }
 
+    fn chunks_vectored_mut<'a>(&'a mut self, dst: &mut [&'a mut UninitSlice]) -> usize {
+        let a_rem = self.a.remaining_mut();
+        let cnt = self.a.chunks_vectored_mut(dst);
+
+        // `b` is only written after `a` is full
+        let a_len: usize = dst[..cnt].iter().map(|e| e.len()).sum();
+        if a_len != a_rem {
+            return cnt;
+        }
+        cnt + self.b.chunks_vectored_mut(&mut dst[cnt..])
+    }
+
     unsafe fn advance_mut(&mut self, cnt: usize) {
         let a_rem = self.a.remaining_mut();
         unsafe { self.a.advance_mut(a_rem.min(cnt)) };
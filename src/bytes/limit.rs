@@ -0,0 +1,72 @@
+use std::{cmp, mem};
+
+use crate::bytes::{BufMut, UninitSlice};
+
+/// A [`BufMut`] adapter which limits the bytes written to an underlying buffer.
+///
+/// This struct is generally created by calling `limit()` on `BufMut`. See documentation of
+/// [`limit()`][BufMut::limit] for more details.
+#[derive(Debug)]
+pub struct Limit<T> {
+    inner: T,
+    limit: usize,
+}
+
+impl<T> Limit<T> {
+    pub(crate) fn new(inner: T, limit: usize) -> Self {
+        Self { inner, limit }
+    }
+
+    /// Returns the maximum number of bytes that can be written.
+    #[inline]
+    pub fn get_limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Sets the maximum number of bytes that can be written.
+    #[inline]
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+    }
+
+    /// Consumes this `Limit`, returns the underlying value.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: BufMut> BufMut for Limit<T> {
+    fn remaining_mut(&self) -> usize {
+        cmp::min(self.inner.remaining_mut(), self.limit)
+    }
+
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        let bytes = self.inner.chunk_mut();
+        let len = cmp::min(bytes.len(), self.limit);
+        &mut bytes[..len]
+    }
+
+    fn chunks_vectored_mut<'a>(&'a mut self, dst: &mut [&'a mut UninitSlice]) -> usize {
+        let cnt = self.inner.chunks_vectored_mut(dst);
+        let mut limit = self.limit;
+
+        for (i, slot) in dst[..cnt].iter_mut().enumerate() {
+            if slot.len() < limit {
+                limit -= slot.len();
+                continue;
+            }
+            let slice = mem::replace(slot, UninitSlice::new(&mut []));
+            *slot = &mut slice[..limit];
+            return if limit == 0 { i } else { i + 1 };
+        }
+        cnt
+    }
+
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        assert!(cnt <= self.limit, "advancing out of limit bound");
+        // SAFETY: the caller guarantees `cnt` bytes are initialized, and the chunk is a subset
+        // of the inner chunk
+        unsafe { self.inner.advance_mut(cnt) };
+        self.limit -= cnt;
+    }
+}
@@ -0,0 +1,148 @@
+use std::ffi::c_void;
+use std::fs::File;
+use std::io;
+use std::ops::{Bound, RangeBounds};
+use std::os::fd::AsRawFd;
+use std::ptr::NonNull;
+use std::slice;
+
+use super::Bytes;
+
+/// Read only memory map, unmapped on drop.
+struct Mmap {
+    ptr: NonNull<c_void>,
+    len: usize,
+}
+
+// SAFETY: the mapping is read only and not tied to any thread
+unsafe impl Send for Mmap {}
+unsafe impl Sync for Mmap {}
+
+impl AsRef<[u8]> for Mmap {
+    fn as_ref(&self) -> &[u8] {
+        // SAFETY: `ptr` is a readable mapping of `len` bytes
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr().cast(), self.len) }
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        // SAFETY: `ptr` and `len` is returned from `mmap`, and never unmapped before
+        unsafe { libc::munmap(self.ptr.as_ptr(), self.len) };
+    }
+}
+
+fn page_size() -> u64 {
+    // SAFETY: `sysconf` have no precondition
+    match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+        size @ 1.. => size as u64,
+        _ => 4096,
+    }
+}
+
+impl Bytes {
+    /// Create new [`Bytes`] by memory mapping the entire `file`.
+    ///
+    /// See [`Bytes::map_range`] for more details.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure that the file is not modified nor truncated while the returned buffer,
+    /// or any of its handle, is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use tcio::bytes::Bytes;
+    ///
+    /// let file = File::open("assets/index.html")?;
+    /// // SAFETY: the file is never modified
+    /// let mut bytes = unsafe { Bytes::map_file(&file)? };
+    ///
+    /// // zero copy
+    /// let head = bytes.split_to(16);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    #[inline]
+    pub unsafe fn map_file(file: &File) -> io::Result<Self> {
+        unsafe { Self::map_range(file, ..) }
+    }
+
+    /// Create new [`Bytes`] by memory mapping the given byte `range` of `file`.
+    ///
+    /// The buffer is read only, and unmapped when the last handle is dropped. Slicing the
+    /// returned buffer, including [`split_to`] and [`copy_to_bytes`], does not copy.
+    ///
+    /// Note that because the buffer is not a `Vec<u8>`, [`Bytes::into_vec`] and
+    /// [`Bytes::into_mut`] always copies the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::ErrorKind::InvalidInput`] if `range` is out of the file length, or any error
+    /// returned by the underlying system call.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure that the file is not modified nor truncated while the returned buffer,
+    /// or any of its handle, is alive.
+    ///
+    /// [`split_to`]: Bytes::split_to
+    /// [`copy_to_bytes`]: crate::bytes::Buf::copy_to_bytes
+    pub unsafe fn map_range(file: &File, range: impl RangeBounds<u64>) -> io::Result<Self> {
+        let file_len = file.metadata()?.len();
+        let start = match range.start_bound() {
+            Bound::Included(&n) => Some(n),
+            Bound::Excluded(&n) => n.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.checked_add(1),
+            Bound::Excluded(&n) => Some(n),
+            Bound::Unbounded => Some(file_len),
+        };
+        let (Some(start), Some(end)) = (start, end) else {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "range out of bounds"));
+        };
+        if start > end || end > file_len {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "range out of bounds"));
+        }
+        if start == end {
+            // `mmap` does not accept zero length
+            return Ok(Self::new());
+        }
+
+        // mapping offset must be a multiple of the page size
+        let offset = start - start % page_size();
+        let Ok(len) = usize::try_from(end - offset) else {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "range too large"));
+        };
+        let Ok(map_offset) = libc::off_t::try_from(offset) else {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "range out of bounds"));
+        };
+
+        // SAFETY: arguments are valid, and the file is not modified as required by the caller
+        let ptr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                len,
+                libc::PROT_READ,
+                libc::MAP_PRIVATE,
+                file.as_raw_fd(),
+                map_offset,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mmap = Mmap {
+            // SAFETY: successful `mmap` never returns null pointer
+            ptr: unsafe { NonNull::new_unchecked(ptr) },
+            len,
+        };
+        let mut bytes = Self::from_owner(mmap);
+        bytes.advance((start - offset) as usize);
+        Ok(bytes)
+    }
+}
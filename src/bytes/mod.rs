@@ -4,33 +4,67 @@
 //!
 //! - [`Buf`] represent a readable in memory buffer.
 //! - [`BufMut`] represent a writable in memory buffer.
+//! - [`BufList`] is a segmented buffer of multiple `Bytes`.
 //! - [`Bytes`] is a reference counted shared memory buffer.
 //! - [`ByteStr`] is a `Bytes` that contains valid UTF-8.
+//! - [`ByteStrMut`] is a `BytesMut` that contains valid UTF-8.
 //! - [`BytesMut`] is a splitable in memory buffer.
+//! - [`BytesPool`] is a pool of recycled `BytesMut` allocation.
+//! - [`RingBuf`] is a fixed capacity circular buffer.
 mod shared;
 
 mod buf;
 mod buf_mut;
+mod buf_list;
+mod ring_buf;
 
 mod bytes;
 mod bytes_mut;
 mod bytestr;
+mod bytestr_mut;
+mod search;
+mod split;
+mod varint;
 mod uninit_slice;
 mod take;
 mod chain;
+mod limit;
+mod reader;
+mod writer;
+mod pool;
+
+#[cfg(all(feature = "mmap", unix))]
+mod mmap;
 
 pub use buf::Buf;
 pub use buf_mut::BufMut;
-pub use bytes::Bytes;
+pub use buf_list::BufList;
+pub use ring_buf::RingBuf;
+pub use bytes::{Bytes, WeakBytes};
 pub use bytes_mut::BytesMut;
 pub use bytestr::ByteStr;
+pub use bytestr_mut::ByteStrMut;
 pub use uninit_slice::UninitSlice;
 pub use take::Take;
 pub use chain::Chain;
+pub use limit::Limit;
+pub use reader::Reader;
+pub use writer::Writer;
+pub use pool::{BytesPool, PoolStats};
+pub use varint::varint_len;
+pub use search::{find_any, find_byte, find_subslice, rfind_any, rfind_byte, rfind_subslice};
+
+pub mod iter {
+    //! Iterator types.
+    pub use super::bytestr::{ByteStrLines, ByteStrSplit};
+    pub use super::split::{Lines, Split, SplitN, SplitStr};
+}
 
 pub mod error {
     //! Error types.
+    pub use super::buf::TryGetError;
     pub use super::bytestr::FromUtf8Error;
+    pub use super::varint::VarintError;
 }
 
 #[cfg(test)]
@@ -0,0 +1,321 @@
+use std::cell::RefCell;
+use std::ptr;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, Weak};
+
+use super::BytesMut;
+
+/// The smallest size class is `2^MIN_CLASS_SHIFT` bytes, 512 B.
+const MIN_CLASS_SHIFT: u32 = 9;
+
+/// The largest size class is `2^MAX_CLASS_SHIFT` bytes, 1 MiB.
+const MAX_CLASS_SHIFT: u32 = 20;
+
+const CLASSES: usize = (MAX_CLASS_SHIFT - MIN_CLASS_SHIFT + 1) as usize;
+
+const DEFAULT_MAX_RETAINED: usize = 64 * 1024 * 1024;
+
+/// A pool of recycled [`BytesMut`] allocations.
+///
+/// Buffers are handed out in power of two size classes, from 512 B up to 1 MiB. When the last
+/// [`Bytes`] or [`BytesMut`] referring to a pooled allocation is dropped, the allocation is given
+/// back to the pool instead of deallocated.
+///
+/// The pool is thread safe, cloning it returns a handle to the same pool.
+///
+/// An allocation is no longer given back to the pool if it is taken out as `Vec<u8>`, such as
+/// via [`Bytes::into_vec`], or if the `BytesMut` reallocates to grow.
+///
+/// # Retained memory
+///
+/// The total capacity of allocations kept by the pool is capped by [`set_max_retained`], the
+/// default is 64 MiB. Allocations given back when the cap is reached are deallocated.
+///
+/// # Thread local cache
+///
+/// Optionally, each thread can keep a small number of allocations per size class, which is
+/// accessed without locking. It is disabled by default, see [`set_local_cache`].
+///
+/// # Examples
+///
+/// ```
+/// use tcio::bytes::BytesPool;
+///
+/// let pool = BytesPool::new();
+///
+/// let mut buf = pool.get(1000);
+/// assert_eq!(buf.capacity(), 1024);
+///
+/// buf.extend_from_slice(b"Hello World!");
+/// let frame = buf.freeze();
+/// let ptr = frame.as_ptr();
+///
+/// // the allocation is given back to the pool
+/// drop(frame);
+/// assert_eq!(pool.stats().retained_bytes, 1024);
+///
+/// // and reused
+/// let buf = pool.get(1024);
+/// assert_eq!(buf.as_ptr(), ptr);
+/// assert_eq!(pool.stats().hits, 1);
+/// ```
+///
+/// [`Bytes`]: super::Bytes
+/// [`Bytes::into_vec`]: super::Bytes::into_vec
+/// [`set_max_retained`]: BytesPool::set_max_retained
+/// [`set_local_cache`]: BytesPool::set_local_cache
+#[derive(Debug, Clone)]
+pub struct BytesPool {
+    inner: Arc<PoolInner>,
+}
+
+/// Statistics of a [`BytesPool`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Number of buffers handed out by reusing a retained allocation.
+    pub hits: u64,
+    /// Number of buffers handed out by allocating.
+    pub misses: u64,
+    /// Total capacity of allocations currently retained by the pool.
+    pub retained_bytes: usize,
+}
+
+impl BytesPool {
+    /// Creates new empty [`BytesPool`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_max_retained(DEFAULT_MAX_RETAINED)
+    }
+
+    /// Creates new empty [`BytesPool`] which retains at most `max_retained` bytes of allocation.
+    pub fn with_max_retained(max_retained: usize) -> Self {
+        Self {
+            inner: Arc::new(PoolInner {
+                classes: Default::default(),
+                max_retained: AtomicUsize::new(max_retained),
+                local_cache: AtomicUsize::new(0),
+                retained: AtomicUsize::new(0),
+                hits: AtomicU64::new(0),
+                misses: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Returns the maximum bytes of allocation retained by the pool.
+    #[inline]
+    pub fn max_retained(&self) -> usize {
+        self.inner.max_retained.load(Ordering::Relaxed)
+    }
+
+    /// Sets the maximum bytes of allocation retained by the pool.
+    ///
+    /// Lowering the limit does not deallocate already retained allocation, use
+    /// [`BytesPool::clear`] for that.
+    #[inline]
+    pub fn set_max_retained(&self, max_retained: usize) {
+        self.inner.max_retained.store(max_retained, Ordering::Relaxed);
+    }
+
+    /// Sets the number of allocations per size class kept in each thread local cache.
+    ///
+    /// Zero, which is the default, disables the thread local cache.
+    ///
+    /// Allocations in the thread local cache still count towards the retained bytes, and given
+    /// back to the shared pool when the thread exits.
+    #[inline]
+    pub fn set_local_cache(&self, per_class: usize) {
+        self.inner.local_cache.store(per_class, Ordering::Relaxed);
+    }
+
+    /// Returns an empty [`BytesMut`] with at least `capacity` bytes of capacity.
+    ///
+    /// The capacity is rounded up to the size class. If `capacity` is larger than the largest
+    /// size class, the returned buffer is not pooled.
+    pub fn get(&self, capacity: usize) -> BytesMut {
+        let Some(class) = class_of(capacity) else {
+            self.inner.misses.fetch_add(1, Ordering::Relaxed);
+            return BytesMut::with_capacity(capacity);
+        };
+
+        let vec = match self.inner.take(class) {
+            Some(vec) => {
+                self.inner.hits.fetch_add(1, Ordering::Relaxed);
+                vec
+            }
+            None => {
+                self.inner.misses.fetch_add(1, Ordering::Relaxed);
+                Vec::with_capacity(class_size(class))
+            }
+        };
+
+        BytesMut::from_pooled(vec, self.inner.clone())
+    }
+
+    /// Returns the statistics of the pool.
+    pub fn stats(&self) -> PoolStats {
+        PoolStats {
+            hits: self.inner.hits.load(Ordering::Relaxed),
+            misses: self.inner.misses.load(Ordering::Relaxed),
+            retained_bytes: self.inner.retained.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Deallocate all retained allocations.
+    ///
+    /// Note that allocations in thread local cache of other threads are not deallocated.
+    pub fn clear(&self) {
+        let mut released = 0;
+        for list in &self.inner.classes {
+            released += lock(list).drain(..).map(|vec| vec.capacity()).sum::<usize>();
+        }
+        let _ = LOCAL.try_with(|local| {
+            let Ok(mut local) = local.try_borrow_mut() else {
+                return;
+            };
+            if let Some(cache) = find_cache(&mut local, &self.inner) {
+                for list in &mut cache.classes {
+                    released += list.drain(..).map(|vec| vec.capacity()).sum::<usize>();
+                }
+            }
+        });
+        self.inner.retained.fetch_sub(released, Ordering::Relaxed);
+    }
+}
+
+impl Default for BytesPool {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ===== Inner =====
+
+#[derive(Debug)]
+pub(crate) struct PoolInner {
+    classes: [Mutex<Vec<Vec<u8>>>; CLASSES],
+    max_retained: AtomicUsize,
+    local_cache: AtomicUsize,
+    retained: AtomicUsize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl PoolInner {
+    fn take(self: &Arc<Self>, class: usize) -> Option<Vec<u8>> {
+        let vec = match self.take_local(class) {
+            Some(vec) => vec,
+            None => lock(&self.classes[class]).pop()?,
+        };
+        self.retained.fetch_sub(vec.capacity(), Ordering::Relaxed);
+        Some(vec)
+    }
+
+    /// Give back the allocation of the last released handle of a pooled buffer.
+    pub(crate) fn recycle(self: &Arc<Self>, vec: Vec<u8>) {
+        let cap = vec.capacity();
+        let Some(class) = class_of(cap).filter(|&class| class_size(class) == cap) else {
+            return;
+        };
+
+        let max = self.max_retained.load(Ordering::Relaxed);
+        let reserved = self.retained.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |len| {
+            len.checked_add(cap).filter(|&len| len <= max)
+        });
+        if reserved.is_err() {
+            return;
+        }
+
+        if let Some(vec) = self.put_local(class, vec) {
+            lock(&self.classes[class]).push(vec);
+        }
+    }
+
+    fn take_local(self: &Arc<Self>, class: usize) -> Option<Vec<u8>> {
+        LOCAL
+            .try_with(|local| {
+                let mut local = local.try_borrow_mut().ok()?;
+                find_cache(&mut local, self)?.classes[class].pop()
+            })
+            .ok()
+            .flatten()
+    }
+
+    /// Returns the allocation back if the thread local cache is disabled or full.
+    fn put_local(self: &Arc<Self>, class: usize, vec: Vec<u8>) -> Option<Vec<u8>> {
+        let per_class = self.local_cache.load(Ordering::Relaxed);
+        if per_class == 0 {
+            return Some(vec);
+        }
+
+        let mut vec = Some(vec);
+        let _ = LOCAL.try_with(|local| {
+            let Ok(mut local) = local.try_borrow_mut() else {
+                return;
+            };
+
+            // remove caches of dropped pools
+            local.retain(|cache| cache.pool.strong_count() != 0);
+
+            let cache = match find_cache(&mut local, self) {
+                Some(cache) => cache,
+                None => {
+                    local.push(LocalCache {
+                        pool: Arc::downgrade(self),
+                        classes: Default::default(),
+                    });
+                    local.last_mut().unwrap()
+                }
+            };
+            let list = &mut cache.classes[class];
+            if list.len() < per_class {
+                list.extend(vec.take());
+            }
+        });
+        vec
+    }
+}
+
+fn class_of(capacity: usize) -> Option<usize> {
+    let size = capacity.max(1 << MIN_CLASS_SHIFT).checked_next_power_of_two()?;
+    let class = (size.trailing_zeros() - MIN_CLASS_SHIFT) as usize;
+    (class < CLASSES).then_some(class)
+}
+
+const fn class_size(class: usize) -> usize {
+    1 << (class as u32 + MIN_CLASS_SHIFT)
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // the list is always in valid state
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+// ===== Thread Local =====
+
+thread_local! {
+    static LOCAL: RefCell<Vec<LocalCache>> = const { RefCell::new(Vec::new()) };
+}
+
+struct LocalCache {
+    pool: Weak<PoolInner>,
+    classes: [Vec<Vec<u8>>; CLASSES],
+}
+
+fn find_cache<'a>(
+    local: &'a mut [LocalCache],
+    pool: &Arc<PoolInner>,
+) -> Option<&'a mut LocalCache> {
+    local.iter_mut().find(|cache| ptr::eq(cache.pool.as_ptr(), Arc::as_ptr(pool)))
+}
+
+impl Drop for LocalCache {
+    fn drop(&mut self) {
+        // give back to the shared pool, otherwise the pool is already dropped
+        if let Some(pool) = self.pool.upgrade() {
+            for (class, list) in self.classes.iter_mut().enumerate() {
+                lock(&pool.classes[class]).append(list);
+            }
+        }
+    }
+}
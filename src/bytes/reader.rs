@@ -0,0 +1,56 @@
+use std::{cmp, io};
+
+use crate::bytes::Buf;
+
+/// A [`Buf`] adapter which implements [`io::Read`] and [`io::BufRead`].
+///
+/// This struct is generally created by calling `reader()` on `Buf`. See documentation of
+/// [`reader()`][Buf::reader] for more details.
+#[derive(Debug)]
+pub struct Reader<B> {
+    buf: B,
+}
+
+impl<B> Reader<B> {
+    pub(crate) fn new(buf: B) -> Self {
+        Self { buf }
+    }
+
+    /// Gets a reference to the underlying `Buf`.
+    #[inline]
+    pub fn get_ref(&self) -> &B {
+        &self.buf
+    }
+
+    /// Gets a mutable reference to the underlying `Buf`.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.buf
+    }
+
+    /// Consumes this `Reader`, returns the underlying value.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+}
+
+impl<B: Buf> io::Read for Reader<B> {
+    fn read(&mut self, dst: &mut [u8]) -> io::Result<usize> {
+        let len = cmp::min(self.buf.remaining(), dst.len());
+        self.buf.copy_to_slice(&mut dst[..len]);
+        Ok(len)
+    }
+}
+
+impl<B: Buf> io::BufRead for Reader<B> {
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.buf.chunk())
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.buf.advance(amt);
+    }
+}
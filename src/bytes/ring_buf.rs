@@ -0,0 +1,251 @@
+use std::io::IoSlice;
+use std::mem::MaybeUninit;
+use std::ops::{Bound, RangeBounds};
+use std::slice;
+
+use crate::bytes::{Buf, BufMut, Bytes, BytesMut, UninitSlice};
+
+/// A fixed capacity circular buffer.
+///
+/// `RingBuf` never reallocates nor moves its content, except when explicitly requested by
+/// [`make_contiguous`]. Written bytes which wrap around the end of the allocation are exposed as
+/// two chunks by [`Buf::chunks_vectored`], and likewise the writable space by
+/// [`BufMut::chunks_vectored_mut`].
+///
+/// # Examples
+///
+/// ```
+/// use tcio::bytes::{Buf, BufMut, RingBuf};
+///
+/// let mut ring = RingBuf::with_capacity(8);
+/// ring.put_slice(b"Hello ");
+/// ring.advance(4);
+///
+/// // wraps around the end
+/// ring.put_slice(b"World!");
+/// assert_eq!(ring.as_slices(), (&b"o Wo"[..], &b"rld!"[..]));
+///
+/// assert_eq!(ring.make_contiguous(), b"o World!");
+/// assert!(!ring.has_remaining_mut());
+/// ```
+///
+/// [`make_contiguous`]: RingBuf::make_contiguous
+pub struct RingBuf {
+    buf: Box<[MaybeUninit<u8>]>,
+    /// INVARIANT: `head < buf.len()`, or zero if the capacity is zero
+    head: usize,
+    /// INVARIANT: `len <= buf.len()`, `len` bytes from `head`, wrapping around, is initialized
+    len: usize,
+}
+
+impl RingBuf {
+    /// Creates new empty [`RingBuf`] with exactly `capacity` bytes of capacity.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: Box::new_uninit_slice(capacity),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of bytes the buffer can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns the number of readable bytes in the buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer contains no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the buffer cannot hold more bytes.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == self.buf.len()
+    }
+
+    /// Removes all bytes.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+
+    /// Returns a pair of slices which contain, in order, the readable bytes.
+    ///
+    /// The second slice is non-empty only if the bytes wrap around the end of the allocation.
+    pub fn as_slices(&self) -> (&[u8], &[u8]) {
+        let first = (self.buf.len() - self.head).min(self.len);
+        // SAFETY: invariant `len` bytes from `head`, wrapping around, is initialized
+        unsafe { (self.slice(self.head, first), self.slice(0, self.len - first)) }
+    }
+
+    /// Rearranges the readable bytes to be contiguous, and returns them.
+    ///
+    /// This is an `O(capacity)` operation if the bytes wrap around the end of the allocation,
+    /// otherwise it does not move the bytes.
+    pub fn make_contiguous(&mut self) -> &mut [u8] {
+        if self.head + self.len > self.buf.len() {
+            // `MaybeUninit` is moved as is, without reading it as `u8`
+            self.buf.rotate_left(self.head);
+            self.head = 0;
+        }
+        let (head, len) = (self.head, self.len);
+        // SAFETY: bytes is not wrapped, invariant `len` bytes from `head` is initialized
+        unsafe { slice::from_raw_parts_mut(self.buf[head..].as_mut_ptr().cast(), len) }
+    }
+
+    /// Copies the given `range` of the readable bytes into new [`Bytes`], without advancing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds of the readable bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tcio::bytes::{BufMut, RingBuf};
+    ///
+    /// let mut ring = RingBuf::with_capacity(16);
+    /// ring.put_slice(b"Hello World!");
+    /// assert_eq!(ring.copy_range(6..11), b"World"[..]);
+    /// assert_eq!(ring.len(), 12);
+    /// ```
+    pub fn copy_range(&self, range: impl RangeBounds<usize>) -> Bytes {
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.checked_add(1).expect("out of range"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.checked_add(1).expect("out of range"),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => self.len,
+        };
+        assert!(start <= end, "range should not be reversed");
+        assert!(end <= self.len, "range out of bounds: {end:?} <= {:?}", self.len);
+
+        let mut bytes = BytesMut::with_capacity(end - start);
+        let (a, b) = self.as_slices();
+        for (slice, offset) in [(a, 0), (b, a.len())] {
+            let from = start.clamp(offset, offset + slice.len()) - offset;
+            let to = end.clamp(offset, offset + slice.len()) - offset;
+            bytes.extend_from_slice(&slice[from..to]);
+        }
+        bytes.freeze()
+    }
+
+    /// Returns the writable regions, in order.
+    fn spare_ranges(&self) -> [(usize, usize); 2] {
+        let cap = self.buf.len();
+        let tail = self.head + self.len;
+        if tail < cap {
+            [(tail, cap), (0, self.head)]
+        } else {
+            [(tail - cap, self.head), (0, 0)]
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `len` bytes from `start` must be initialized.
+    unsafe fn slice(&self, start: usize, len: usize) -> &[u8] {
+        let slice = &self.buf[start..start + len];
+        // SAFETY: the caller guarantees the bytes is initialized
+        unsafe { slice::from_raw_parts(slice.as_ptr().cast(), len) }
+    }
+}
+
+impl Buf for RingBuf {
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        self.as_slices().0
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        assert!(cnt <= self.len, "cannot advance past `remaining`: {cnt:?} <= {:?}", self.len);
+        self.len -= cnt;
+        if self.len == 0 {
+            // maximize the contiguous writable space
+            self.head = 0;
+        } else {
+            self.head = (self.head + cnt) % self.buf.len();
+        }
+    }
+
+    fn chunks_vectored<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        let mut cnt = 0;
+        let (a, b) = self.as_slices();
+        for (slot, slice) in dst.iter_mut().zip([a, b]) {
+            if slice.is_empty() {
+                break;
+            }
+            *slot = IoSlice::new(slice);
+            cnt += 1;
+        }
+        cnt
+    }
+}
+
+impl BufMut for RingBuf {
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        self.buf.len() - self.len
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        let [(start, end), _] = self.spare_ranges();
+        UninitSlice::from_uninit(&mut self.buf[start..end])
+    }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        assert!(
+            cnt <= self.remaining_mut(),
+            "cannot advance past `remaining_mut`: {cnt:?} <= {:?}",
+            self.remaining_mut(),
+        );
+        self.len += cnt;
+    }
+
+    fn chunks_vectored_mut<'a>(&'a mut self, dst: &mut [&'a mut UninitSlice]) -> usize {
+        let [(a_start, a_end), (b_start, b_end)] = self.spare_ranges();
+        let (front, back) = self.buf.split_at_mut(a_start);
+        let a = &mut back[..a_end - a_start];
+        let b = &mut front[b_start..b_end];
+
+        let mut cnt = 0;
+        for (slot, slice) in dst.iter_mut().zip([a, b]) {
+            if slice.is_empty() {
+                break;
+            }
+            *slot = UninitSlice::from_uninit(slice);
+            cnt += 1;
+        }
+        cnt
+    }
+}
+
+impl std::fmt::Debug for RingBuf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RingBuf")
+            .field("len", &self.len)
+            .field("capacity", &self.buf.len())
+            .finish()
+    }
+}
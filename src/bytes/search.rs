@@ -0,0 +1,222 @@
+//! Byte search functions.
+//!
+//! The search is processed a word at a time (SWAR) where possible.
+const WORD: usize = size_of::<usize>();
+
+const LO: usize = usize::from_ne_bytes([0x01; WORD]);
+const HI: usize = usize::from_ne_bytes([0x80; WORD]);
+
+/// Returns the word with every byte set to `byte`.
+#[inline(always)]
+const fn repeat(byte: u8) -> usize {
+    LO * byte as usize
+}
+
+/// Returns `true` if any byte in `word` is zero.
+#[inline(always)]
+const fn has_zero(word: usize) -> bool {
+    word.wrapping_sub(LO) & !word & HI != 0
+}
+
+#[inline(always)]
+fn read_word(chunk: &[u8]) -> usize {
+    usize::from_ne_bytes(chunk.try_into().expect("chunk is a word"))
+}
+
+/// Returns the index of the first occurrence of `needle` in `haystack`.
+///
+/// # Examples
+///
+/// ```
+/// use tcio::bytes::find_byte;
+///
+/// assert_eq!(find_byte(b"Hello World!", b'o'), Some(4));
+/// assert_eq!(find_byte(b"Hello World!", b'x'), None);
+/// ```
+pub fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    let pattern = repeat(needle);
+    let mut chunks = haystack.chunks_exact(WORD);
+
+    for (i, chunk) in chunks.by_ref().enumerate() {
+        if has_zero(read_word(chunk) ^ pattern) {
+            let pos = chunk.iter().position(|&b| b == needle).expect("word contains needle");
+            return Some(i * WORD + pos);
+        }
+    }
+
+    let offset = haystack.len() - chunks.remainder().len();
+    chunks.remainder().iter().position(|&b| b == needle).map(|pos| offset + pos)
+}
+
+/// Returns the index of the last occurrence of `needle` in `haystack`.
+///
+/// # Examples
+///
+/// ```
+/// use tcio::bytes::rfind_byte;
+///
+/// assert_eq!(rfind_byte(b"Hello World!", b'o'), Some(7));
+/// assert_eq!(rfind_byte(b"Hello World!", b'x'), None);
+/// ```
+pub fn rfind_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    let pattern = repeat(needle);
+    let mut chunks = haystack.rchunks_exact(WORD);
+
+    for (i, chunk) in chunks.by_ref().enumerate() {
+        if has_zero(read_word(chunk) ^ pattern) {
+            let pos = chunk.iter().rposition(|&b| b == needle).expect("word contains needle");
+            return Some(haystack.len() - (i + 1) * WORD + pos);
+        }
+    }
+
+    chunks.remainder().iter().rposition(|&b| b == needle)
+}
+
+/// Returns the index of the first occurrence of any of the `needles` in `haystack`.
+///
+/// # Examples
+///
+/// ```
+/// use tcio::bytes::find_any;
+///
+/// assert_eq!(find_any(b"key=value;next", b";="), Some(3));
+/// assert_eq!(find_any(b"key=value;next", b""), None);
+/// ```
+pub fn find_any(haystack: &[u8], needles: &[u8]) -> Option<usize> {
+    match *needles {
+        [] => None,
+        [needle] => find_byte(haystack, needle),
+        [_, _] | [_, _, _] => {
+            let mut chunks = haystack.chunks_exact(WORD);
+
+            for (i, chunk) in chunks.by_ref().enumerate() {
+                let word = read_word(chunk);
+                if needles.iter().any(|&needle| has_zero(word ^ repeat(needle))) {
+                    let pos = chunk.iter().position(|b| needles.contains(b));
+                    return Some(i * WORD + pos.expect("word contains needle"));
+                }
+            }
+
+            let offset = haystack.len() - chunks.remainder().len();
+            let pos = chunks.remainder().iter().position(|b| needles.contains(b))?;
+            Some(offset + pos)
+        }
+        _ => {
+            let table = ByteSet::new(needles);
+            haystack.iter().position(|&b| table.contains(b))
+        }
+    }
+}
+
+/// Returns the index of the last occurrence of any of the `needles` in `haystack`.
+///
+/// # Examples
+///
+/// ```
+/// use tcio::bytes::rfind_any;
+///
+/// assert_eq!(rfind_any(b"key=value;next", b";="), Some(9));
+/// ```
+pub fn rfind_any(haystack: &[u8], needles: &[u8]) -> Option<usize> {
+    match *needles {
+        [] => None,
+        [needle] => rfind_byte(haystack, needle),
+        [_, _] | [_, _, _] => {
+            let mut chunks = haystack.rchunks_exact(WORD);
+
+            for (i, chunk) in chunks.by_ref().enumerate() {
+                let word = read_word(chunk);
+                if needles.iter().any(|&needle| has_zero(word ^ repeat(needle))) {
+                    let pos = chunk.iter().rposition(|b| needles.contains(b));
+                    let offset = haystack.len() - (i + 1) * WORD;
+                    return Some(offset + pos.expect("word contains needle"));
+                }
+            }
+
+            chunks.remainder().iter().rposition(|b| needles.contains(b))
+        }
+        _ => {
+            let table = ByteSet::new(needles);
+            haystack.iter().rposition(|&b| table.contains(b))
+        }
+    }
+}
+
+/// Returns the index of the first occurrence of `needle` slice in `haystack`.
+///
+/// Empty `needle` matches at index `0`.
+///
+/// # Examples
+///
+/// ```
+/// use tcio::bytes::find_subslice;
+///
+/// assert_eq!(find_subslice(b"host: a\r\n\r\nbody", b"\r\n\r\n"), Some(7));
+/// assert_eq!(find_subslice(b"host: a\r\n", b"\r\n\r\n"), None);
+/// ```
+pub fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let Some((&first, rest)) = needle.split_first() else {
+        return Some(0);
+    };
+    // the last index where the needle can start
+    let last = haystack.len().checked_sub(needle.len())?;
+
+    let mut start = 0;
+    while let Some(pos) = find_byte(&haystack[start..=last], first) {
+        let at = start + pos;
+        if haystack[at + 1..].starts_with(rest) {
+            return Some(at);
+        }
+        start = at + 1;
+    }
+    None
+}
+
+/// Returns the index of the last occurrence of `needle` slice in `haystack`.
+///
+/// Empty `needle` matches at index `haystack.len()`.
+///
+/// # Examples
+///
+/// ```
+/// use tcio::bytes::rfind_subslice;
+///
+/// assert_eq!(rfind_subslice(b"a\r\nb\r\nc", b"\r\n"), Some(4));
+/// ```
+pub fn rfind_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let Some((&last, rest)) = needle.split_last() else {
+        return Some(haystack.len());
+    };
+    if haystack.len() < needle.len() {
+        return None;
+    }
+
+    // the last byte of the needle is searched in `[rest.len(), end)`, so the index found
+    // relative to the range is where the needle starts
+    let mut end = haystack.len();
+    while let Some(at) = rfind_byte(&haystack[rest.len()..end], last) {
+        if haystack[..at + rest.len()].ends_with(rest) {
+            return Some(at);
+        }
+        end = at + rest.len();
+    }
+    None
+}
+
+/// A set of bytes as a lookup table.
+struct ByteSet([bool; 256]);
+
+impl ByteSet {
+    fn new(bytes: &[u8]) -> Self {
+        let mut table = [false; 256];
+        for &b in bytes {
+            table[b as usize] = true;
+        }
+        Self(table)
+    }
+
+    #[inline(always)]
+    fn contains(&self, byte: u8) -> bool {
+        self.0[byte as usize]
+    }
+}
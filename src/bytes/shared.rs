@@ -1,4 +1,6 @@
-use std::{ptr::{self, NonNull}, sync::atomic::AtomicUsize};
+use std::{any::Any, cell::UnsafeCell, ptr::{self, NonNull}, sync::{Arc, atomic::AtomicUsize}};
+
+use super::pool::PoolInner;
 
 /// even number alignment means the LSB is always unset
 ///
@@ -17,8 +19,24 @@ const _: [(); 1] = [(); RESERVED_BIT_DATA];
 #[derive(Debug)]
 pub struct Shared {
     ref_count: AtomicUsize,
+    /// Number of weak handles, plus one which is collectively held by all the strong handles.
+    weak_count: AtomicUsize,
     ptr: NonNull<u8>,
     cap: usize,
+    /// Only accessed by strong handles, and taken over when the last one is released, while weak
+    /// handles may still observe `Shared`.
+    storage: UnsafeCell<Storage>,
+}
+
+/// How the buffer is deallocated when the last handle is released.
+#[derive(Debug)]
+enum Storage {
+    /// The buffer is a `Vec<u8>`.
+    Vec,
+    /// The buffer is owned by this value instead of a `Vec<u8>`.
+    Owner(Box<dyn Any + Send + Sync>),
+    /// The buffer is a `Vec<u8>` which is given back to the pool.
+    Pooled(Arc<PoolInner>),
 }
 
 impl Shared {
@@ -67,22 +85,6 @@ pub fn to_unpromoted(data: *const Shared) -> Option<usize> {
     }
 }
 
-pub fn as_unpromoted_mut<'a>(data: *mut Shared) -> Result<usize, &'a mut Shared> {
-    if is_unpromoted(data) {
-        Ok(data as usize >> RESERVED_BIT_DATA)
-    } else {
-        Err(unsafe { &mut *data })
-    }
-}
-
-pub fn into_unpromoted(data: *mut Shared) -> Result<usize, Box<Shared>> {
-    if is_unpromoted(data) {
-        Ok(data as usize >> RESERVED_BIT_DATA)
-    } else {
-        Err(unsafe { Box::from_raw(data) })
-    }
-}
-
 // ===== Unpromoted =====
 
 /// Mask the arbitrary payload with `usize`.
@@ -115,24 +117,106 @@ pub fn promote_with_vec(mut vec: Vec<u8>, ref_count: usize) -> *mut Shared {
 
     let shared = Shared {
         ref_count: AtomicUsize::new(ref_count),
+        weak_count: AtomicUsize::new(1),
         ptr,
         cap,
+        storage: UnsafeCell::new(Storage::Vec),
     };
 
     Box::into_raw(Box::new(shared))
 }
 
+/// Returns the promoted `Shared` with single handle, which give back the buffer to the `pool` on
+/// release instead of deallocating it.
+pub fn promote_with_pool(mut vec: Vec<u8>, pool: Arc<PoolInner>) -> *mut Shared {
+    let cap = vec.capacity();
+    let ptr = unsafe { NonNull::new_unchecked(vec.as_mut_ptr()) };
+
+    // prevent heap deallocation
+    let _vec = std::mem::ManuallyDrop::new(vec);
+
+    let shared = Shared {
+        ref_count: AtomicUsize::new(1),
+        weak_count: AtomicUsize::new(1),
+        ptr,
+        cap,
+        storage: UnsafeCell::new(Storage::Pooled(pool)),
+    };
+
+    Box::into_raw(Box::new(shared))
+}
+
+/// Returns the promoted `Shared` with the buffer of the given `owner`, and the buffer length.
+///
+/// The `owner` is dropped when the last handle is released.
+pub fn promote_with_owner<T>(owner: T) -> (*mut Shared, usize)
+where
+    T: AsRef<[u8]> + Send + Sync + 'static,
+{
+    // the owner is boxed first, so that moving it does not invalidate the buffer pointer,
+    // e.g. an array
+    let owner = Box::new(owner);
+    let buf = (*owner).as_ref();
+    let ptr = unsafe { NonNull::new_unchecked(buf.as_ptr().cast_mut()) };
+    let len = buf.len();
+
+    let shared = Shared {
+        ref_count: AtomicUsize::new(1),
+        weak_count: AtomicUsize::new(1),
+        ptr,
+        cap: len,
+        storage: UnsafeCell::new(Storage::Owner(owner)),
+    };
+
+    (Box::into_raw(Box::new(shared)), len)
+}
+
 // ===== Promoted =====
 
+/// Weak count value while it is locked by [`has_ref_count`].
+const WEAK_LOCKED: usize = usize::MAX;
+
+/// Returns `true` if there is exactly one handle of the shared buffer, and no weak handle.
 pub fn is_unique(shared: &Shared) -> bool {
+    has_ref_count(shared, 1)
+}
+
+/// Returns `true` if there is exactly two handles of the shared buffer, and no weak handle.
+///
+/// This is used when the caller have exclusive access to both handles.
+pub fn is_pair(shared: &Shared) -> bool {
+    has_ref_count(shared, 2)
+}
+
+// follow the `is_unique` procedure from `Arc`
+fn has_ref_count(shared: &Shared, ref_count: usize) -> bool {
     use std::sync::atomic::Ordering;
+
+    // lock the weak count, so that no weak handle can be upgraded between the two checks, weak
+    // handle is only created from a strong handle, which is exclusively owned by the caller
+    //
+    // The `Acquire` ordering synchronizes with the `Release` decrement in `release_weak`, so that
+    // any `upgrade` of an already dropped weak handle is visible to the current thread.
+    if shared
+        .weak_count
+        .compare_exchange(1, WEAK_LOCKED, Ordering::Acquire, Ordering::Relaxed)
+        .is_err()
+    {
+        return false;
+    }
+
     // The `Acquire` ordering synchronizes with the `Release` as
     // part of the `fetch_sub` in `Shared::release`. The `fetch_sub`
     // operation guarantees that any mutations done in other threads
     // are ordered before the `ref_count` is decremented. As such,
     // this `Acquire` will guarantee that those mutations are
     // visible to the current thread.
-    shared.ref_count.load(Ordering::Acquire) == 1
+    let result = shared.ref_count.load(Ordering::Acquire) == ref_count;
+
+    // The `Release` write here synchronizes with a read in `downgrade`, effectively preventing
+    // the above read of `ref_count` from happening after the write.
+    shared.weak_count.store(1, Ordering::Release);
+    result
 }
 
 // follow the clone procedure from `Arc`
@@ -156,14 +240,104 @@ pub fn increment(shared: &Shared) {
     }
 }
 
-#[allow(clippy::boxed_local, reason = "`Shared` always in the heap")]
-pub fn release(shared: Box<Shared>) {
+/// Increment the weak count, the caller must have a strong or weak handle.
+pub fn downgrade(shared: &Shared) {
+    use std::sync::atomic::Ordering;
+
+    // follow the downgrade procedure from `Arc`
+    let mut cur = shared.weak_count.load(Ordering::Relaxed);
+    loop {
+        // the weak count is locked by `has_ref_count`, spin
+        if cur == WEAK_LOCKED {
+            std::hint::spin_loop();
+            cur = shared.weak_count.load(Ordering::Relaxed);
+            continue;
+        }
+
+        if cur > isize::MAX as usize {
+            std::process::abort();
+        }
+
+        // The `Acquire` ordering synchronize with the `Release` write in `has_ref_count`.
+        match shared.weak_count.compare_exchange_weak(
+            cur,
+            cur + 1,
+            Ordering::Acquire,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => return,
+            Err(old) => cur = old,
+        }
+    }
+}
+
+/// Try to increment the reference count from a weak handle.
+///
+/// Returns `false` if the buffer is already released.
+pub fn upgrade(shared: &Shared) -> bool {
+    use std::sync::atomic::Ordering;
+
+    // follow the upgrade procedure from `Arc`, the reference count is never incremented from
+    // zero, and the `Acquire` ordering synchronizes with the `Release` in `release`
+    let mut cur = shared.ref_count.load(Ordering::Relaxed);
+    loop {
+        if cur == 0 {
+            return false;
+        }
+
+        if cur > isize::MAX as usize {
+            std::process::abort();
+        }
+
+        match shared.ref_count.compare_exchange_weak(
+            cur,
+            cur + 1,
+            Ordering::Acquire,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => return true,
+            Err(old) => cur = old,
+        }
+    }
+}
+
+/// Release a weak handle, deallocate `Shared` if it is the last one.
+///
+/// # Safety
+///
+/// `shared` must be a promoted pointer, which the caller holds a weak handle of. The handle must
+/// not be used after this call.
+pub unsafe fn release_weak(shared: *mut Shared) {
+    use std::sync::atomic::Ordering;
+
+    // `Shared` is only accessed through shared reference until the last weak handle, because
+    // other weak handles may still observe it
+    let shared_ref = unsafe { &*shared };
+
+    if shared_ref.weak_count.fetch_sub(1, Ordering::Release) != 1 {
+        return;
+    }
+
+    // The same as in `release`.
+    shared_ref.weak_count.load(Ordering::Acquire);
+
+    // SAFETY: this is the last handle of `Shared`
+    drop(unsafe { Box::from_raw(shared) });
+}
+
+/// Release a strong handle, the buffer is dropped if it is the last one.
+///
+/// # Safety
+///
+/// `shared` must be a promoted pointer, which the caller holds a strong handle of. The handle
+/// must not be used after this call.
+pub unsafe fn release(shared: *mut Shared) {
     use std::sync::atomic::Ordering;
 
+    let shared_ref = unsafe { &*shared };
+
     // follow the drop procedure from `Arc`
-    if shared.ref_count.fetch_sub(1, Ordering::Release) != 1 {
-        // do not deallocate the heap
-        let _shared = Box::into_raw(shared);
+    if shared_ref.ref_count.fetch_sub(1, Ordering::Release) != 1 {
         return;
     }
 
@@ -186,32 +360,78 @@ pub fn release(shared: Box<Shared>) {
     // [1]: (www.boost.org/doc/libs/1_55_0/doc/html/atomic/usage_examples.html)
 
     // ThreadSanitizer does not support memory fences.
-    shared.ref_count.load(Ordering::Acquire);
+    shared_ref.ref_count.load(Ordering::Acquire);
 
-    unsafe {
-        drop(Vec::from_raw_parts(shared.ptr.as_ptr(), 0, shared.cap));
+    // `Shared` itself may outlive the buffer if there is weak handle, so the storage is taken
+    // over instead of dropping `Shared`
+    //
+    // SAFETY: this is the last strong handle, weak handles never access the storage
+    match unsafe { shared_ref.storage.get().replace(Storage::Vec) } {
+        Storage::Vec => unsafe {
+            drop(Vec::from_raw_parts(shared_ref.ptr.as_ptr(), 0, shared_ref.cap));
+        },
+        Storage::Owner(owner) => drop(owner),
+        Storage::Pooled(pool) => unsafe {
+            pool.recycle(Vec::from_raw_parts(shared_ref.ptr.as_ptr(), 0, shared_ref.cap));
+        },
     }
+
+    // release the weak handle collectively held by strong handles
+    unsafe { release_weak(shared) };
 }
 
-/// Release the `Shared` handle, if the reference is unique, returns the underlying buffer with
-/// given length of initialized data.
+/// Release the `Shared` handle if it is the last strong handle, and returns the underlying buffer
+/// with given length of initialized data.
+///
+/// Returns `None` if there is other strong handle or the buffer is owned by an owner other than
+/// `Vec<u8>`, in which case the handle is *not* released.
 ///
 /// # Safety
 ///
+/// The same as [`release`], if the handle is released.
+///
 /// Caller must ensure that `len` of data is initialized.
-#[allow(clippy::boxed_local, reason = "`Shared` always in the heap")]
-pub unsafe fn release_into_vec(shared: Box<Shared>, len: usize) -> Option<Vec<u8>> {
+///
+/// If the buffer is pooled, the returned buffer is no longer given back to the pool.
+pub unsafe fn release_into_vec(shared: *mut Shared, len: usize) -> Option<Vec<u8>> {
     use std::sync::atomic::Ordering;
 
-    if shared.ref_count.fetch_sub(1, Ordering::Release) != 1 {
-        // do not deallocate the heap
-        let _shared = Box::into_raw(shared);
+    let shared_ref = unsafe { &*shared };
+
+    // SAFETY: caller holds a strong handle, so the storage is not taken over
+    if let Storage::Owner(owner) = unsafe { &*shared_ref.storage.get() }
+        && !owner.is::<Vec<u8>>()
+    {
         return None;
     }
 
-    shared.ref_count.load(Ordering::Acquire);
-
-    unsafe {
-        Some(Vec::from_raw_parts(shared.ptr.as_ptr(), len, shared.cap))
+    // the reference count is only decremented if this is the last strong handle, otherwise the
+    // handle is kept so that the caller can copy the buffer before releasing it
+    //
+    // The `Acquire` ordering synchronizes with the `Release` in `release`, and upgrading a weak
+    // handle never increments the reference count from zero.
+    if shared_ref
+        .ref_count
+        .compare_exchange(1, 0, Ordering::Acquire, Ordering::Relaxed)
+        .is_err()
+    {
+        return None;
     }
+
+    // SAFETY: this is the last strong handle, weak handles never access the storage
+    let vec = match unsafe { shared_ref.storage.get().replace(Storage::Vec) } {
+        Storage::Owner(owner) => {
+            let mut vec = *owner.downcast::<Vec<u8>>().expect("owner is a `Vec<u8>`");
+            vec.truncate(len);
+            vec
+        }
+        // detach from the pool, if any
+        Storage::Vec | Storage::Pooled(_) => unsafe {
+            Vec::from_raw_parts(shared_ref.ptr.as_ptr(), len, shared_ref.cap)
+        },
+    };
+
+    unsafe { release_weak(shared) };
+
+    Some(vec)
 }
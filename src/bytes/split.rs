@@ -0,0 +1,229 @@
+use super::Bytes;
+use super::search::{find_byte, find_subslice};
+
+impl Bytes {
+    /// Returns an iterator over subslices separated by `delimiter`.
+    ///
+    /// Each subslice shares the allocation with `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tcio::bytes::Bytes;
+    /// let bytes = Bytes::copy_from_slice(b"gzip,deflate,");
+    /// let mut iter = bytes.split_byte(b',');
+    /// assert_eq!(iter.next().unwrap(), b"gzip"[..]);
+    /// assert_eq!(iter.next().unwrap(), b"deflate"[..]);
+    /// assert_eq!(iter.next().unwrap(), b""[..]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn split_byte(&self, delimiter: u8) -> Split<'_> {
+        Split {
+            bytes: self,
+            rest: Some(self.as_slice()),
+            delimiter,
+        }
+    }
+
+    /// Returns an iterator over subslices separated by `delimiter` slice.
+    ///
+    /// Each subslice shares the allocation with `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delimiter` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tcio::bytes::Bytes;
+    /// let bytes = Bytes::copy_from_slice(b"a\r\nb\r\n");
+    /// let mut iter = bytes.split_str(b"\r\n");
+    /// assert_eq!(iter.next().unwrap(), b"a"[..]);
+    /// assert_eq!(iter.next().unwrap(), b"b"[..]);
+    /// assert_eq!(iter.next().unwrap(), b""[..]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn split_str<'a>(&'a self, delimiter: &'a [u8]) -> SplitStr<'a> {
+        assert!(!delimiter.is_empty(), "`split_str` delimiter is empty");
+        SplitStr {
+            bytes: self,
+            rest: Some(self.as_slice()),
+            delimiter,
+        }
+    }
+
+    /// Returns an iterator over subslices separated by `delimiter`, limited to return at most
+    /// `n` items.
+    ///
+    /// The last item returned contains the remaining bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tcio::bytes::Bytes;
+    /// let bytes = Bytes::copy_from_slice(b"GET / HTTP/1.1");
+    /// let mut iter = bytes.splitn_byte(2, b' ');
+    /// assert_eq!(iter.next().unwrap(), b"GET"[..]);
+    /// assert_eq!(iter.next().unwrap(), b"/ HTTP/1.1"[..]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn splitn_byte(&self, n: usize, delimiter: u8) -> SplitN<'_> {
+        SplitN {
+            inner: self.split_byte(delimiter),
+            count: n,
+        }
+    }
+
+    /// Returns an iterator over the lines of the bytes.
+    ///
+    /// Lines are split at `\n` or `\r\n`, the line terminator is not included. The final line
+    /// ending is optional, a trailing line terminator does not produce an empty line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tcio::bytes::Bytes;
+    /// let bytes = Bytes::copy_from_slice(b"host: a\r\naccept: */*\n");
+    /// let mut iter = bytes.lines();
+    /// assert_eq!(iter.next().unwrap(), b"host: a"[..]);
+    /// assert_eq!(iter.next().unwrap(), b"accept: */*"[..]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn lines(&self) -> Lines<'_> {
+        Lines {
+            bytes: self,
+            rest: self.as_slice(),
+        }
+    }
+}
+
+/// An iterator over subslices of [`Bytes`] separated by a delimiter.
+///
+/// This struct is created by [`Bytes::split_byte`].
+#[derive(Debug, Clone)]
+pub struct Split<'a> {
+    bytes: &'a Bytes,
+    /// `None` if the iterator is exhausted
+    rest: Option<&'a [u8]>,
+    delimiter: u8,
+}
+
+impl Split<'_> {
+    /// Returns the remaining bytes, without splitting.
+    fn finish(&mut self) -> Option<Bytes> {
+        self.rest.take().map(|rest| self.bytes.slice_ref(rest))
+    }
+}
+
+impl Iterator for Split<'_> {
+    type Item = Bytes;
+
+    fn next(&mut self) -> Option<Bytes> {
+        let rest = self.rest?;
+        match find_byte(rest, self.delimiter) {
+            Some(i) => {
+                self.rest = Some(&rest[i + 1..]);
+                Some(self.bytes.slice_ref(&rest[..i]))
+            }
+            None => self.finish(),
+        }
+    }
+}
+
+impl std::iter::FusedIterator for Split<'_> {}
+
+/// An iterator over subslices of [`Bytes`] separated by a delimiter slice.
+///
+/// This struct is created by [`Bytes::split_str`].
+#[derive(Debug, Clone)]
+pub struct SplitStr<'a> {
+    bytes: &'a Bytes,
+    /// `None` if the iterator is exhausted
+    rest: Option<&'a [u8]>,
+    delimiter: &'a [u8],
+}
+
+impl Iterator for SplitStr<'_> {
+    type Item = Bytes;
+
+    fn next(&mut self) -> Option<Bytes> {
+        let rest = self.rest?;
+        match find_subslice(rest, self.delimiter) {
+            Some(i) => {
+                self.rest = Some(&rest[i + self.delimiter.len()..]);
+                Some(self.bytes.slice_ref(&rest[..i]))
+            }
+            None => {
+                self.rest = None;
+                Some(self.bytes.slice_ref(rest))
+            }
+        }
+    }
+}
+
+impl std::iter::FusedIterator for SplitStr<'_> {}
+
+/// An iterator over subslices of [`Bytes`] separated by a delimiter, limited to return at most
+/// `n` items.
+///
+/// This struct is created by [`Bytes::splitn_byte`].
+#[derive(Debug, Clone)]
+pub struct SplitN<'a> {
+    inner: Split<'a>,
+    count: usize,
+}
+
+impl Iterator for SplitN<'_> {
+    type Item = Bytes;
+
+    fn next(&mut self) -> Option<Bytes> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.inner.finish()
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next()
+            }
+        }
+    }
+}
+
+impl std::iter::FusedIterator for SplitN<'_> {}
+
+/// An iterator over the lines of [`Bytes`].
+///
+/// This struct is created by [`Bytes::lines`].
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    bytes: &'a Bytes,
+    rest: &'a [u8],
+}
+
+impl Iterator for Lines<'_> {
+    type Item = Bytes;
+
+    fn next(&mut self) -> Option<Bytes> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let line = match find_byte(self.rest, b'\n') {
+            Some(i) => {
+                let (line, rest) = (&self.rest[..i], &self.rest[i + 1..]);
+                self.rest = rest;
+                line.strip_suffix(b"\r").unwrap_or(line)
+            }
+            None => std::mem::take(&mut self.rest),
+        };
+        Some(self.bytes.slice_ref(line))
+    }
+}
+
+impl std::iter::FusedIterator for Lines<'_> {}
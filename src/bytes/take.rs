@@ -54,7 +54,7 @@ impl<T: Buf> Buf for Take<T> {
             .chunks_vectored(&mut slices[..dst.len().min(LEN)]);
         let mut limit = self.limit;
 
-        for (i, (dst, slice)) in dst[..cnt].iter_mut().zip(slices.into_iter()).enumerate() {
+        for (i, (dst, slice)) in dst[..cnt].iter_mut().zip(slices).enumerate() {
             if let Some(buf) = slice.get(..limit) {
                 // cannot use the unstable `IoSlice::as_slice`
                 let buf = unsafe { mem::transmute::<&[u8], &'a [u8]>(buf) };
@@ -69,6 +69,13 @@ impl<T: Buf> Buf for Take<T> {
         cnt
     }
 
+    fn peek_at(&self, offset: usize, dst: &mut [u8]) -> bool {
+        if offset.checked_add(dst.len()).is_none_or(|end| end > self.limit) {
+            return false;
+        }
+        self.inner.peek_at(offset, dst)
+    }
+
     fn copy_to_bytes(&mut self, len: usize) -> super::Bytes {
         assert!(len <= self.limit, "`len` is out of limit bound");
         let bytes = self.inner.copy_to_bytes(len);
@@ -1,2 +1,14 @@
+mod test_buf;
+mod test_buf_list;
 mod test_bytes;
 mod test_bytes_mut;
+mod test_bytestr;
+mod test_bytestr_mut;
+mod test_either;
+mod test_pool;
+mod test_ring_buf;
+mod test_search;
+mod test_varint;
+mod test_weak;
+#[cfg(all(feature = "mmap", unix))]
+mod test_mmap;
@@ -0,0 +1,313 @@
+use std::io::IoSlice;
+
+use crate::bytes::error::TryGetError;
+use crate::bytes::{Buf, BufList, BufMut, Bytes};
+
+/// `Buf` of disjoint segments which only override `chunks_vectored` if `vectored` is `true`.
+pub(super) struct Segments {
+    pub(super) segments: Vec<&'static [u8]>,
+    pub(super) vectored: bool,
+}
+
+impl Buf for Segments {
+    fn remaining(&self) -> usize {
+        self.segments.iter().map(|e| e.len()).sum()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.segments.first().copied().unwrap_or_default()
+    }
+
+    fn advance(&mut self, mut cnt: usize) {
+        while cnt != 0 {
+            let first = &mut self.segments[0];
+            let n = cnt.min(first.len());
+            *first = &first[n..];
+            cnt -= n;
+            if first.is_empty() {
+                self.segments.remove(0);
+            }
+        }
+    }
+
+    fn chunks_vectored<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        if !self.vectored {
+            return match (dst.first_mut(), self.segments.first()) {
+                (Some(dst), Some(src)) => {
+                    *dst = IoSlice::new(src);
+                    1
+                }
+                _ => 0,
+            };
+        }
+        let cnt = dst.len().min(self.segments.len());
+        for (dst, src) in dst.iter_mut().zip(&self.segments) {
+            *dst = IoSlice::new(src);
+        }
+        cnt
+    }
+}
+
+#[test]
+fn test_try_get_short() {
+    let mut buf = &b"\x01\x02\x03"[..];
+    assert_eq!(buf.try_get_u32(), None);
+    assert_eq!(buf.try_get_i64_le(), None);
+    assert_eq!(buf.remaining(), 3);
+    assert_eq!(buf.try_get_u16(), Some(0x0102));
+    assert_eq!(buf.try_get_u16(), None);
+    assert_eq!(buf.try_get_u8(), Some(0x03));
+    assert_eq!(buf.try_get_u8(), None);
+
+    // non-contiguous buffer
+    let mut buf = Bytes::from_static(b"\x01\x02").chain(Bytes::from_static(b"\x03"));
+    assert_eq!(buf.try_get_u32_le(), None);
+    assert_eq!(buf.remaining(), 3);
+    assert_eq!(buf.try_get_u16_le(), Some(0x0201));
+}
+
+#[test]
+fn test_checked_get() {
+    let mut buf = Bytes::from_static(b"\x00\x00\x01\x00\xff");
+
+    let err = buf.checked_get_u64().unwrap_err();
+    assert_eq!(err, TryGetError { requested: 8, available: 5 });
+    assert_eq!(err.missing(), 3);
+    assert_eq!(buf.remaining(), 5);
+
+    assert_eq!(buf.checked_get_u32(), Ok(0x100));
+    assert_eq!(buf.checked_get_i16(), Err(TryGetError { requested: 2, available: 1 }));
+    assert_eq!(buf.checked_get_i8(), Ok(-1));
+    assert_eq!(buf.checked_get_u8(), Err(TryGetError { requested: 1, available: 0 }));
+
+    let err = std::io::Error::from(err);
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn test_peek() {
+    let buf = &b"\x01\x02\x03\x04\x05"[..];
+    assert_eq!(buf.peek_u8(), Some(0x01));
+    assert_eq!(buf.peek_i8(), Some(0x01));
+    assert_eq!(buf.peek_u16(), Some(0x0102));
+    assert_eq!(buf.peek_u32_le(), Some(0x04030201));
+    assert_eq!(buf.peek_u32_ne(), Some(u32::from_ne_bytes([1, 2, 3, 4])));
+    assert_eq!(buf.peek_u64(), None);
+    assert_eq!(buf.remaining(), 5);
+
+    let mut dst = [0u8; 2];
+    assert!(buf.peek_at(3, &mut dst));
+    assert_eq!(dst, [4, 5]);
+    assert!(!buf.peek_at(4, &mut dst));
+    assert!(!buf.peek_at(usize::MAX, &mut dst));
+    assert!(buf.peek_at(5, &mut []));
+
+    let empty = &b""[..];
+    assert_eq!(empty.peek_u8(), None);
+}
+
+#[test]
+fn test_peek_non_contiguous() {
+    let buf = (&b"\x01"[..]).chain(&b"\x02\x03"[..]).chain(&b"\x04\x05\x06"[..]);
+    assert_eq!(buf.peek_u32(), Some(0x01020304));
+    assert_eq!(buf.peek_i16_le(), Some(0x0201));
+    assert_eq!(buf.chunk(), b"\x01");
+
+    let mut dst = [0u8; 4];
+    assert!(buf.peek_at(2, &mut dst));
+    assert_eq!(dst, [3, 4, 5, 6]);
+    assert!(!buf.peek_at(3, &mut dst));
+
+    // limit is respected across segments
+    let take = buf.take(4);
+    assert_eq!(take.peek_u32(), Some(0x01020304));
+    assert!(!take.peek_at(1, &mut dst));
+    assert!(take.peek_at(1, &mut dst[..3]));
+    assert_eq!(dst[..3], [2, 3, 4]);
+
+    let list: BufList = [&b"\x01\x02"[..], b"\x03", b"\x04\x05"]
+        .into_iter()
+        .map(Bytes::from_static)
+        .collect();
+    assert_eq!(list.peek_u128(), None);
+    assert_eq!(list.peek_u32(), Some(0x01020304));
+    assert!(list.peek_at(1, &mut dst));
+    assert_eq!(dst, [2, 3, 4, 5]);
+    assert_eq!(list.segments(), 3);
+}
+
+#[test]
+fn test_peek_segments() {
+    let mut buf = Segments { segments: vec![b"\x01", b"\x02"], vectored: true };
+    assert_eq!(buf.peek_u16(), Some(0x0102));
+    assert_eq!(buf.peek_u32(), None);
+    buf.advance(1);
+    assert_eq!(buf.peek_u8(), Some(0x02));
+}
+
+#[test]
+#[should_panic]
+fn test_peek_segments_not_covered() {
+    let buf = Segments { segments: vec![b"\x01", b"\x02"], vectored: false };
+    buf.peek_u16();
+}
+
+#[test]
+fn test_float() {
+    let mut buf = vec![];
+    buf.put_f32(1.5);
+    buf.put_f64_le(-0.25);
+    buf.put_f32_ne(f32::INFINITY);
+    assert_eq!(buf[..4], [0x3f, 0xc0, 0, 0]);
+
+    let mut buf = &buf[..];
+    assert_eq!(buf.peek_f32(), Some(1.5));
+    assert_eq!(buf.get_f32(), 1.5);
+    assert_eq!(buf.try_get_f64_le(), Some(-0.25));
+    assert_eq!(buf.try_get_f64_ne(), None);
+    assert_eq!(buf.checked_get_f32_ne(), Ok(f32::INFINITY));
+}
+
+#[test]
+fn test_uint() {
+    let mut buf = vec![];
+    buf.put_uint(0x010203, 3);
+    buf.put_uint_le(0x010203, 3);
+    buf.put_int(-2, 3);
+    buf.put_int_le(-2, 2);
+    buf.put_uint(u64::MAX, 0);
+    buf.put_uint_ne(0x0102, 2);
+    assert_eq!(buf[..11], [1, 2, 3, 3, 2, 1, 0xff, 0xff, 0xfe, 0xfe, 0xff]);
+    assert_eq!(buf[11..], 0x0102u16.to_ne_bytes());
+
+    let mut buf = (&buf[..4]).chain(&buf[4..]);
+    assert_eq!(buf.get_uint(3), 0x010203);
+    assert_eq!(buf.get_uint_le(3), 0x010203);
+    assert_eq!(buf.get_int(3), -2);
+    assert_eq!(buf.get_int_le(2), -2);
+    assert_eq!(buf.get_uint(0), 0);
+    assert_eq!(buf.try_get_uint_ne(3), None);
+    assert_eq!(buf.remaining(), 2);
+    assert_eq!(buf.try_get_uint_ne(2), Some(0x0102));
+
+    let mut buf = &b"\x80\x00\x00\x00\x00\x00\x00\x00"[..];
+    assert_eq!(buf.try_get_int(8), Some(i64::MIN));
+}
+
+#[test]
+#[should_panic]
+fn test_uint_too_wide() {
+    (&[0u8; 16][..]).get_uint(9);
+}
+
+#[test]
+fn test_put_bytes() {
+    let mut buf = [0u8; 6];
+    let mut dst = &mut buf[..];
+    dst.put_bytes(b'a', 4);
+    dst.put_bytes(b'b', 0);
+    dst.put_bytes(b'c', 2);
+    assert_eq!(&buf, b"aaaacc");
+
+    let mut a = [0u8; 2];
+    let mut b = [0u8; 3];
+    let mut chain = (&mut a[..]).chain_mut(&mut b[..]);
+    chain.put_bytes(0x7f, 5);
+    assert_eq!((a, b), ([0x7f; 2], [0x7f; 3]));
+}
+
+#[test]
+fn test_reader() {
+    use std::io::{BufRead, Read};
+
+    let world = BufList::from(Bytes::from_static(b"World!"));
+    let buf = Buf::chain(Bytes::from_static(b"Hello\n"), world);
+    let mut reader = buf.reader();
+
+    let mut line = String::new();
+    assert_eq!(reader.read_line(&mut line).unwrap(), 6);
+    assert_eq!(line, "Hello\n");
+
+    let mut dst = [0u8; 4];
+    assert_eq!(reader.read(&mut dst).unwrap(), 4);
+    assert_eq!(&dst, b"Worl");
+
+    let mut rest = vec![];
+    assert_eq!(reader.read_to_end(&mut rest).unwrap(), 2);
+    assert_eq!(rest, b"d!");
+    assert_eq!(reader.read(&mut dst).unwrap(), 0);
+    assert!(!reader.get_ref().has_remaining());
+
+    let mut reader = Buf::take(Bytes::from_static(b"Hello World!"), 5).reader();
+    let mut dst = String::new();
+    reader.read_to_string(&mut dst).unwrap();
+    assert_eq!(dst, "Hello");
+    assert_eq!(reader.into_inner().into_inner(), b" World!"[..]);
+}
+
+#[test]
+fn test_writer_limit() {
+    use std::io::Write;
+
+    let mut limit = Vec::new().limit(16);
+    assert_eq!(limit.remaining_mut(), 16);
+    limit.put_slice(b"Hello ");
+    assert_eq!(limit.get_limit(), 10);
+
+    let mut writer = limit.writer();
+    write!(writer, "World!").unwrap();
+    assert!(write!(writer, " Goodbye!").is_err());
+    writer.flush().unwrap();
+
+    let mut limit = writer.into_inner();
+    assert_eq!(limit.remaining_mut(), 0);
+    limit.set_limit(1);
+    limit.put_u8(b'.');
+    assert_eq!(limit.into_inner(), b"Hello World! Goo.");
+
+    let mut buf = [0u8; 4];
+    let mut writer = (&mut buf[..]).writer();
+    assert_eq!(writer.write(b"abcdef").unwrap(), 4);
+    assert_eq!(writer.write(b"ef").unwrap(), 0);
+    assert_eq!(&buf, b"abcd");
+}
+
+#[test]
+fn test_chunks_vectored_mut() {
+    use crate::bytes::{BytesMut, UninitSlice};
+
+    fn lens(buf: &mut impl BufMut) -> Vec<usize> {
+        let mut dst = [(); 8].map(|_| UninitSlice::new(&mut []));
+        let cnt = buf.chunks_vectored_mut(&mut dst);
+        dst[..cnt].iter().map(|e| e.len()).collect()
+    }
+
+    let mut a = [0u8; 2];
+    let mut b = [0u8; 3];
+    let mut c = [0u8; 4];
+    let mut chain = (&mut a[..]).chain_mut(&mut b[..]).chain_mut(&mut c[..]);
+    assert_eq!(lens(&mut chain), [2, 3, 4]);
+    assert_eq!(lens(&mut (&mut chain).limit(4)), [2, 2]);
+    assert_eq!(lens(&mut (&mut chain).limit(5)), [2, 3]);
+    assert_eq!(lens(&mut (&mut chain).limit(0)), [0usize; 0]);
+
+    // writing across the slices is sequential
+    let mut dst = [(); 8].map(|_| UninitSlice::new(&mut []));
+    let cnt = chain.chunks_vectored_mut(&mut dst);
+    for (i, slice) in dst[..cnt].iter_mut().enumerate() {
+        slice.copy_from_slice(&vec![i as u8 + 1; slice.len()]);
+    }
+    unsafe { chain.advance_mut(6) };
+    assert_eq!(lens(&mut chain), [3]);
+    assert_eq!((a, b), ([1, 1], [2, 2, 2]));
+    assert_eq!(c[0], 3);
+
+    // the first buffer does not have fixed capacity, so the second is never exposed
+    let mut second = [0u8; 4];
+    let mut chain = BytesMut::with_capacity(8).chain_mut(&mut second[..]);
+    assert_eq!(lens(&mut chain).len(), 1);
+
+    let mut empty: &mut [u8] = &mut [];
+    assert_eq!(lens(&mut empty), [0usize; 0]);
+    assert_eq!(empty.chunks_vectored_mut(&mut []), 0);
+}
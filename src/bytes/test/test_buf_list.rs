@@ -0,0 +1,106 @@
+use std::io::IoSlice;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+use crate::bytes::{Buf, BufList, Bytes};
+use crate::io::AsyncWrite;
+use crate::io::test::Recorder;
+
+fn list() -> BufList {
+    let mut list = BufList::new();
+    list.push_back(Bytes::copy_from_slice(b"Hello"));
+    list.push_back(Bytes::new());
+    list.push_back(Bytes::copy_from_slice(b" World"));
+    list.push_back(Bytes::copy_from_slice(b"!"));
+    list
+}
+
+#[test]
+fn test_buf_list() {
+    let mut list = list();
+    assert_eq!(list.remaining(), 12);
+    assert_eq!(list.segments(), 3);
+    assert_eq!(list.chunk(), b"Hello");
+
+    let mut dst = [IoSlice::new(&[]); 4];
+    assert_eq!(list.chunks_vectored(&mut dst), 3);
+    assert_eq!(&*dst[1], b" World");
+    assert_eq!(list.chunks_vectored(&mut dst[..2]), 2);
+
+    list.advance(7);
+    assert_eq!(list.segments(), 2);
+    assert_eq!(list.chunk(), b"orld");
+
+    let mut buf = [0u8; 5];
+    list.copy_to_slice(&mut buf);
+    assert_eq!(&buf, b"orld!");
+    assert!(list.is_empty());
+    assert_eq!(list.segments(), 0);
+    assert_eq!(list.chunk(), b"");
+}
+
+#[test]
+fn test_buf_list_copy_to_bytes() {
+    let mut list = list();
+    let ptr = list.chunk().as_ptr();
+
+    // within the first segment
+    let hel = list.copy_to_bytes(3);
+    assert_eq!(hel, b"Hel"[..]);
+    assert_eq!(hel.as_ptr(), ptr);
+
+    let lo = list.copy_to_bytes(2);
+    assert_eq!(lo.as_ptr(), ptr.wrapping_add(3));
+    assert_eq!(list.segments(), 2);
+
+    // across segments
+    let rest = list.copy_to_bytes(7);
+    assert_eq!(rest, b" World!"[..]);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_buf_list_split_to() {
+    let mut list = list();
+    list.push_front(Bytes::from_static(b"> "));
+
+    let head = list.split_to(9);
+    assert_eq!(head.remaining(), 9);
+    assert_eq!(head.segments(), 3);
+    let head: Vec<Vec<u8>> = head.into_iter().map(Bytes::into_vec).collect();
+    assert_eq!(head, [&b"> "[..], b"Hello", b" W"]);
+
+    assert_eq!(list.remaining(), 5);
+    assert_eq!(list.chunk(), b"orld");
+
+    let empty = list.split_to(0);
+    assert!(empty.is_empty());
+
+    let all = list.split_to(5);
+    assert_eq!(all.segments(), 2);
+    assert!(list.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_buf_list_split_to_oob() {
+    list().split_to(13);
+}
+
+#[test]
+fn test_write_buf_list_vectored() {
+    let mut list: BufList = [&b"HTTP/1.1 200 OK\r\n"[..], b"content-length: 5\r\n\r\n", b"Hello"]
+        .into_iter()
+        .map(Bytes::from_static)
+        .collect();
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut io = Recorder::new(true, 20);
+    while list.has_remaining() {
+        let poll = Pin::new(&mut io).poll_write_buf_vectored(&mut list, &mut cx);
+        assert!(matches!(poll, Poll::Ready(Ok(()))));
+    }
+
+    assert_eq!(io.written, b"HTTP/1.1 200 OK\r\ncontent-length: 5\r\n\r\nHello");
+    assert_eq!(io.writes, 3);
+}
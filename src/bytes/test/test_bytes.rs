@@ -585,10 +585,118 @@ fn test_bytes_from_mut() {
     assert_eq!(vec.as_ptr(), ptr);
 }
 
+// Owner
+
+struct Owner {
+    buf: [u8; 12],
+    dropped: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl AsRef<[u8]> for Owner {
+    fn as_ref(&self) -> &[u8] {
+        &self.buf
+    }
+}
+
+impl Drop for Owner {
+    fn drop(&mut self) {
+        self.dropped.store(true, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+fn owned_bytes() -> (Bytes, std::sync::Arc<std::sync::atomic::AtomicBool>) {
+    let dropped = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let owner = Owner { buf: *b"Hello World!", dropped: dropped.clone() };
+    (Bytes::from_owner(owner), dropped)
+}
+
+#[test]
+fn test_bytes_from_owner() {
+    use std::sync::atomic::Ordering;
+
+    let (mut buf, dropped) = owned_bytes();
+    buf.assert_promoted();
+    assert!(buf.is_unique());
+    assert_eq!(buf, b"Hello World!"[..]);
+
+    let hello = buf.split_to(5);
+    let cloned = buf.clone();
+    let world = buf.slice(1..);
+    assert!(!buf.is_unique());
+    assert_eq!(hello, b"Hello"[..]);
+    assert_eq!(cloned, b" World!"[..]);
+    assert_eq!(world, b"World!"[..]);
+
+    drop(buf);
+    drop(hello);
+    drop(cloned);
+    assert!(!dropped.load(Ordering::Relaxed));
+    assert_eq!(world, b"World!"[..]);
+
+    drop(world);
+    assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn test_bytes_from_owner_into_vec() {
+    use std::sync::atomic::Ordering;
+
+    // unique, still copied
+    let (buf, dropped) = owned_bytes();
+    let ptr = buf.as_ptr();
+    let vec = buf.into_vec();
+    assert_ne!(vec.as_ptr(), ptr);
+    assert_eq!(vec, b"Hello World!");
+    assert!(dropped.load(Ordering::Relaxed));
+
+    // shared
+    let (mut buf, dropped) = owned_bytes();
+    let hello = buf.split_to(5);
+    let bufm = hello.into_mut();
+    assert_eq!(&bufm[..], b"Hello");
+    assert!(!dropped.load(Ordering::Relaxed));
+
+    let vec = buf.into_vec();
+    assert_eq!(vec, b" World!");
+    assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn test_bytes_from_owner_vec() {
+    let mut owner = Vec::with_capacity(32);
+    owner.extend_from_slice(b"Hello World!");
+    let ptr = owner.as_ptr();
+
+    // unique `Vec<u8>` owner is reused
+    let mut buf = Bytes::from_owner(owner);
+    buf.advance(6);
+    let vec = buf.into_vec();
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec.capacity(), 32);
+    assert_eq!(vec, b"World!");
+
+    let mut buf = Bytes::from_owner(vec);
+    let world = buf.split_off(1);
+    assert_eq!(buf, b"W"[..]);
+    drop(buf);
+    let bufm = world.into_mut();
+    assert_eq!(bufm.as_ptr(), ptr.wrapping_add(1));
+    assert_eq!(&bufm[..], b"orld!");
+
+    // shared `Vec<u8>` owner is copied
+    let mut buf = Bytes::from_owner(b"Hello".to_vec());
+    let hello = buf.clone();
+    let bufm = buf.split_to(2).into_mut();
+    assert_ne!(bufm.as_ptr(), hello.as_ptr());
+    assert_eq!(&bufm[..], b"He");
+    assert_eq!(buf.into_vec(), b"llo");
+    assert_eq!(hello, b"Hello"[..]);
+}
+
 impl Bytes {
     #[cfg(test)]
     #[doc(hidden)]
-    fn assert_promoted(&self) {
+    pub(super) fn assert_promoted(&self) {
         let ptr = self
             .data()
             .load(std::sync::atomic::Ordering::Acquire)
@@ -599,7 +707,7 @@ impl Bytes {
 
     #[cfg(test)]
     #[doc(hidden)]
-    fn assert_unpromoted(&self) {
+    pub(super) fn assert_unpromoted(&self) {
         let ptr = self
             .data()
             .load(std::sync::atomic::Ordering::Acquire)
@@ -0,0 +1,85 @@
+use crate::bytes::ByteStr;
+
+fn assert_shared(sub: &ByteStr, origin: &ByteStr) {
+    let start = origin.as_ptr().addr();
+    let addr = sub.as_ptr().addr();
+    assert!(addr >= start && addr + sub.len() <= start + origin.len());
+}
+
+#[test]
+fn test_bytestr_split() {
+    let text = ByteStr::copy_from_str("GET /index.html HTTP/1.1");
+
+    let (method, rest) = text.split_once_bytestr(" ").unwrap();
+    assert_eq!(method.as_str(), "GET");
+    assert_eq!(rest.as_str(), "/index.html HTTP/1.1");
+    assert_shared(&rest, &text);
+
+    let (target, version) = text.rsplit_once_bytestr(" ").unwrap();
+    assert_eq!(target.as_str(), "GET /index.html");
+    assert_eq!(version.as_str(), "HTTP/1.1");
+    assert!(text.split_once_bytestr("\r\n").is_none());
+
+    let parts: Vec<ByteStr> = text.split_bytestr(" ").collect();
+    let parts_str: Vec<&str> = parts.iter().map(ByteStr::as_str).collect();
+    assert_eq!(parts_str, ["GET", "/index.html", "HTTP/1.1"]);
+    for part in &parts {
+        assert_shared(part, &text);
+    }
+}
+
+#[test]
+fn test_bytestr_lines_trim() {
+    let text = ByteStr::copy_from_str("  host: example.com \r\naccept: */*\n\n");
+
+    let lines: Vec<ByteStr> = text.lines_bytestr().collect();
+    let lines_str: Vec<&str> = lines.iter().map(ByteStr::as_str).collect();
+    assert_eq!(lines_str, ["  host: example.com ", "accept: */*", ""]);
+    assert_eq!(text.lines_bytestr().next_back().unwrap().as_str(), "");
+
+    let host = &lines[0];
+    assert_eq!(host.trim_bytestr().as_str(), "host: example.com");
+    assert_eq!(host.trim_start_bytestr().as_str(), "host: example.com ");
+    assert_eq!(host.trim_end_bytestr().as_str(), "  host: example.com");
+    assert_shared(&host.trim_bytestr(), &text);
+
+    let accept = &lines[1];
+    assert_eq!(accept.strip_prefix_bytestr("accept: ").unwrap().as_str(), "*/*");
+    assert_eq!(accept.strip_suffix_bytestr("*/*").unwrap().as_str(), "accept: ");
+    assert!(accept.strip_prefix_bytestr("host").is_none());
+    assert!(accept.strip_suffix_bytestr("host").is_none());
+}
+
+#[test]
+fn test_bytestr_split_boundary() {
+    let mut text = ByteStr::copy_from_str("αβγδ");
+
+    assert!(text.split_to(1).is_none());
+    assert!(text.split_to(9).is_none());
+    assert!(text.split_off(3).is_none());
+    assert_eq!(text.as_str(), "αβγδ");
+
+    let alpha = text.split_to(2).unwrap();
+    let delta = text.split_off(4).unwrap();
+    assert_eq!(alpha.as_str(), "α");
+    assert_eq!(text.as_str(), "βγ");
+    assert_eq!(delta.as_str(), "δ");
+
+    assert_eq!(text.get_bytestr(2..).unwrap().as_str(), "γ");
+    assert_eq!(text.get_bytestr(..).unwrap().as_str(), "βγ");
+    assert!(text.get_bytestr(..1).is_none());
+    assert!(text.get_bytestr(..9).is_none());
+}
+
+#[test]
+fn test_bytestr_deref_str_methods() {
+    // `str` methods are still reachable via `Deref`
+    let text = ByteStr::copy_from_str(" a,b ");
+    let trimmed: &str = text.trim();
+    assert_eq!(trimmed, "a,b");
+    assert_eq!(trimmed.split(',').count(), 2);
+    assert_eq!(text.split(',').collect::<Vec<_>>(), [" a", "b "]);
+    assert_eq!(text.lines().count(), 1);
+    assert_eq!(text.get(1..2), Some("a"));
+    assert_eq!(text.strip_prefix(' '), Some("a,b "));
+}
@@ -0,0 +1,67 @@
+use std::fmt::Write;
+
+use crate::bytes::{ByteStr, ByteStrMut};
+
+#[test]
+fn test_bytestr_mut_write() {
+    let mut string = ByteStrMut::new();
+    string.push('α');
+    string.push_str("bc");
+    write!(string, "-{}-", 42).unwrap();
+    string.extend(["x", "y"]);
+    string.extend(['z']);
+    assert_eq!(string.as_str(), "αbc-42-xyz");
+    assert_eq!(string.len(), 11);
+
+    string.truncate(2);
+    assert_eq!(string.as_str(), "α");
+    string.clear();
+    assert!(string.is_empty());
+}
+
+#[test]
+fn test_bytestr_mut_split_freeze() {
+    let mut string = ByteStrMut::with_capacity(32);
+    string.push_str("GET /index.html");
+    let ptr = string.as_ptr();
+
+    let method = string.split_to(3);
+    let path = string.split_off(1);
+    assert_eq!(method.as_str(), "GET");
+    assert_eq!(string.as_str(), " ");
+    assert_eq!(path.as_str(), "/index.html");
+
+    // freezing shares the allocation
+    let method: ByteStr = method.freeze();
+    let path = path.freeze();
+    assert_eq!(method.as_ptr(), ptr);
+    assert_eq!(path.as_ptr(), ptr.wrapping_add(4));
+    assert_eq!(path.as_str(), "/index.html");
+}
+
+#[test]
+#[should_panic]
+fn test_bytestr_mut_split_char_boundary() {
+    let mut string = ByteStrMut::copy_from_str("αβ");
+    string.split_to(1);
+}
+
+#[test]
+fn test_bytestr_into_mut() {
+    let string = ByteStr::copy_from_str("Content-Type");
+    let ptr = string.as_ptr();
+
+    // unique is reused
+    let mut string = string.into_mut();
+    assert_eq!(string.as_ptr(), ptr);
+    string.make_ascii_lowercase();
+    string.push_str(": text/plain");
+    assert_eq!(string.as_str(), "content-type: text/plain");
+
+    // shared is copied
+    let string = string.freeze();
+    let cloned = string.clone();
+    let copied = cloned.into_mut();
+    assert_ne!(copied.as_ptr(), string.as_ptr());
+    assert_eq!(copied.as_str(), string.as_str());
+}
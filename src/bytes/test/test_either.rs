@@ -0,0 +1,62 @@
+use std::io::IoSlice;
+
+use crate::bytes::{Buf, BufMut, Bytes, BytesMut, Chain};
+use crate::either::Either;
+
+fn encode(split: bool) -> Either<Bytes, Chain<Bytes, Bytes>> {
+    let bytes = Bytes::copy_from_slice(b"Hello World!");
+    if split {
+        let mut head = bytes.clone();
+        let tail = head.split_off(5);
+        Either::Right(head.chain(tail))
+    } else {
+        Either::Left(bytes)
+    }
+}
+
+#[test]
+fn test_either_buf() {
+    for split in [false, true] {
+        let mut buf = encode(split);
+        assert_eq!(buf.remaining(), 12);
+
+        let mut slices = [IoSlice::new(&[]); 4];
+        let cnt = buf.chunks_vectored(&mut slices);
+        assert_eq!(cnt, 1 + split as usize);
+        let joined: Vec<u8> = slices[..cnt].iter().flat_map(|s| s.iter().copied()).collect();
+        assert_eq!(joined, b"Hello World!");
+
+        assert_eq!(buf.get_u8(), b'H');
+        let hello = buf.copy_to_bytes(4);
+        assert_eq!(hello, b"ello"[..]);
+        buf.advance(1);
+        assert_eq!(buf.chunk(), b"World!");
+    }
+}
+
+#[test]
+fn test_either_buf_zero_copy() {
+    let mut buf = encode(false);
+    let Either::Left(bytes) = &buf else { unreachable!() };
+    let ptr = bytes.as_ptr();
+
+    let hello = buf.copy_to_bytes(5);
+    assert_eq!(hello.as_ptr(), ptr);
+}
+
+#[test]
+fn test_either_buf_mut() {
+    let mut buf = Either::<Vec<u8>, BytesMut>::Left(Vec::new());
+    buf.put_u16(0x4142);
+    buf.put(&b"CD"[..]);
+    buf.put_slice(b"EF");
+    let Either::Left(vec) = &buf else { unreachable!() };
+    assert_eq!(vec, b"ABCDEF");
+
+    let mut storage = [0u8; 4];
+    let mut buf = Either::<Vec<u8>, &mut [u8]>::Right(&mut storage[..]);
+    assert_eq!(buf.remaining_mut(), 4);
+    buf.put_u32_le(0x44434241);
+    assert!(!buf.has_remaining_mut());
+    assert_eq!(&storage, b"ABCD");
+}
@@ -0,0 +1,78 @@
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use crate::bytes::{Buf, Bytes};
+
+/// Temporary file removed on drop.
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str, content: &[u8]) -> (Self, File) {
+        let path = std::env::temp_dir().join(format!("tcio-{}-{name}", std::process::id()));
+        File::create(&path).unwrap().write_all(content).unwrap();
+        let file = File::open(&path).unwrap();
+        (Self(path), file)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn test_map_file() {
+    let (_tmp, file) = TempFile::new("map-file", b"Hello World!");
+    let mut bytes = unsafe { Bytes::map_file(&file).unwrap() };
+    drop(file);
+
+    assert_eq!(bytes, b"Hello World!"[..]);
+
+    let ptr = bytes.as_ptr();
+    let hello = bytes.split_to(5);
+    assert_eq!(hello.as_ptr(), ptr);
+    assert_eq!(hello, b"Hello"[..]);
+
+    let space = bytes.copy_to_bytes(1);
+    assert_eq!(space.as_ptr(), ptr.wrapping_add(5));
+
+    let world = bytes.slice(..5);
+    assert_eq!(world.as_ptr(), ptr.wrapping_add(6));
+    assert_eq!(world, b"World"[..]);
+
+    drop(bytes);
+    drop(hello);
+    assert_eq!(world, b"World"[..]);
+    assert_eq!(world.into_vec(), b"World");
+}
+
+#[test]
+fn test_map_range() {
+    // span multiple pages, so the range start is not page aligned
+    let content: Vec<u8> = (0..20_000u32).map(|e| e as u8).collect();
+    let (_tmp, file) = TempFile::new("map-range", &content);
+
+    let bytes = unsafe { Bytes::map_range(&file, 5000..12_345).unwrap() };
+    assert_eq!(bytes, content[5000..12_345]);
+
+    let bytes = unsafe { Bytes::map_range(&file, 19_999..).unwrap() };
+    assert_eq!(bytes, content[19_999..]);
+
+    let bytes = unsafe { Bytes::map_range(&file, 7..=7).unwrap() };
+    assert_eq!(bytes, content[7..8]);
+
+    let bytes = unsafe { Bytes::map_range(&file, 20_000..).unwrap() };
+    assert!(bytes.is_empty());
+
+    let err = unsafe { Bytes::map_range(&file, 0..20_001).unwrap_err() };
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn test_map_empty_file() {
+    let (_tmp, file) = TempFile::new("map-empty", b"");
+    let bytes = unsafe { Bytes::map_file(&file).unwrap() };
+    assert!(bytes.is_empty());
+}
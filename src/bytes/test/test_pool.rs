@@ -0,0 +1,121 @@
+use crate::bytes::{BytesMut, BytesPool, PoolStats};
+
+#[test]
+fn test_pool_size_class() {
+    let pool = BytesPool::new();
+
+    assert_eq!(pool.get(0).capacity(), 512);
+    assert_eq!(pool.get(512).capacity(), 512);
+    assert_eq!(pool.get(513).capacity(), 1024);
+    assert_eq!(pool.get(1024 * 1024).capacity(), 1024 * 1024);
+
+    // larger than the largest class is not pooled
+    let stats = pool.stats();
+    let buf = pool.get(1024 * 1024 + 1);
+    assert!(buf.capacity() > 1024 * 1024);
+    drop(buf);
+    assert_eq!(pool.stats().retained_bytes, stats.retained_bytes);
+    assert_eq!(pool.stats().misses, stats.misses + 1);
+}
+
+#[test]
+fn test_pool_recycle_split() {
+    let pool = BytesPool::new();
+
+    let mut buf = pool.get(1024);
+    let ptr = buf.as_ptr();
+    buf.extend_from_slice(b"Hello World!");
+
+    let hello = buf.split_to(5).freeze();
+    let world = hello.clone();
+    drop(buf);
+    drop(hello);
+    assert_eq!(pool.stats().retained_bytes, 0);
+
+    // the last handle give back the allocation
+    drop(world);
+    assert_eq!(
+        pool.stats(),
+        PoolStats {
+            hits: 0,
+            misses: 1,
+            retained_bytes: 1024
+        }
+    );
+
+    let buf = pool.get(600);
+    assert_eq!(buf.as_ptr(), ptr);
+    assert!(buf.is_empty());
+    assert_eq!(
+        pool.stats(),
+        PoolStats {
+            hits: 1,
+            misses: 1,
+            retained_bytes: 0
+        }
+    );
+}
+
+#[test]
+fn test_pool_detach() {
+    let pool = BytesPool::new();
+
+    // taken out as `Vec`
+    let mut buf = pool.get(512);
+    buf.extend_from_slice(b"detach");
+    let vec = buf.freeze().into_vec();
+    assert_eq!(vec, b"detach");
+    drop(vec);
+    assert_eq!(pool.stats().retained_bytes, 0);
+
+    // reallocating give back the old allocation
+    let mut buf = pool.get(512);
+    buf.extend_from_slice(&[1; 600]);
+    assert_eq!(pool.stats().retained_bytes, 512);
+    drop(buf);
+    assert_eq!(pool.stats().retained_bytes, 512);
+}
+
+#[test]
+fn test_pool_max_retained() {
+    let pool = BytesPool::with_max_retained(1024);
+
+    let bufs: Vec<BytesMut> = (0..3).map(|_| pool.get(512)).collect();
+    drop(bufs);
+    assert_eq!(pool.stats().retained_bytes, 1024);
+
+    pool.clear();
+    assert_eq!(pool.stats().retained_bytes, 0);
+    assert_eq!(pool.get(512).capacity(), 512);
+    assert_eq!(pool.stats().hits, 0);
+}
+
+#[test]
+fn test_pool_local_cache() {
+    let pool = BytesPool::new();
+    pool.set_local_cache(1);
+
+    let a = pool.get(512);
+    let b = pool.get(512);
+    let (a_ptr, b_ptr) = (a.as_ptr(), b.as_ptr().addr());
+    drop(a);
+    drop(b);
+    assert_eq!(pool.stats().retained_bytes, 1024);
+
+    // the thread local cache is preferred
+    let a = pool.get(512);
+    assert_eq!(a.as_ptr(), a_ptr);
+
+    // buffer from other thread
+    let other = pool.clone();
+    std::thread::spawn(move || {
+        let buf = other.get(512);
+        assert_eq!(buf.as_ptr().addr(), b_ptr);
+        // given back to the shared pool when the thread exits
+        drop(buf);
+    })
+    .join()
+    .unwrap();
+    assert_eq!(pool.get(512).as_ptr().addr(), b_ptr);
+    assert_eq!(pool.stats().hits, 3);
+}
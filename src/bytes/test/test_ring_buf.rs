@@ -0,0 +1,85 @@
+use std::io::IoSlice;
+
+use crate::bytes::{Buf, BufMut, RingBuf, UninitSlice};
+
+fn spare_lens(ring: &mut RingBuf) -> Vec<usize> {
+    let mut dst = [(); 4].map(|_| UninitSlice::new(&mut []));
+    let cnt = ring.chunks_vectored_mut(&mut dst);
+    dst[..cnt].iter().map(|e| e.len()).collect()
+}
+
+#[test]
+fn test_ring_buf_wrap() {
+    let mut ring = RingBuf::with_capacity(8);
+    assert_eq!(ring.capacity(), 8);
+    assert_eq!(spare_lens(&mut ring), [8]);
+
+    ring.put_slice(b"abcdef");
+    ring.advance(4);
+    assert_eq!(ring.chunk(), b"ef");
+    assert_eq!(ring.remaining_mut(), 6);
+    assert_eq!(ring.chunk_mut().len(), 2);
+    assert_eq!(spare_lens(&mut ring), [2, 4]);
+
+    // write across the end
+    ring.put_slice(b"ghijk");
+    assert_eq!(ring.as_slices(), (&b"efgh"[..], &b"ijk"[..]));
+    assert_eq!(spare_lens(&mut ring), [1]);
+
+    let mut dst = [IoSlice::new(&[]); 4];
+    assert_eq!(ring.chunks_vectored(&mut dst), 2);
+    assert_eq!((&*dst[0], &*dst[1]), (&b"efgh"[..], &b"ijk"[..]));
+
+    let mut out = [0u8; 6];
+    ring.copy_to_slice(&mut out);
+    assert_eq!(&out, b"efghij");
+    assert_eq!(ring.as_slices(), (&b"k"[..], &b""[..]));
+
+    ring.put_slice(b"lmnopqr");
+    assert!(ring.is_full());
+    assert_eq!(spare_lens(&mut ring), [0usize; 0]);
+    assert_eq!(ring.copy_to_bytes(8), b"klmnopqr"[..]);
+    assert!(ring.is_empty());
+
+    // the head is reset when empty
+    assert_eq!(ring.chunk_mut().len(), 8);
+}
+
+#[test]
+#[should_panic]
+fn test_ring_buf_overflow() {
+    let mut ring = RingBuf::with_capacity(4);
+    ring.put_slice(b"abcde");
+}
+
+#[test]
+fn test_ring_buf_contiguous() {
+    let mut ring = RingBuf::with_capacity(6);
+    ring.put_slice(b"abcd");
+    ring.advance(3);
+    let ptr = ring.chunk().as_ptr();
+    assert_eq!(ring.make_contiguous(), b"d");
+    assert_eq!(ring.chunk().as_ptr(), ptr);
+
+    ring.put_slice(b"efghi");
+    assert_eq!(ring.as_slices(), (&b"def"[..], &b"ghi"[..]));
+    assert_eq!(ring.copy_range(..), b"defghi"[..]);
+    assert_eq!(ring.copy_range(2..5), b"fgh"[..]);
+    assert_eq!(ring.copy_range(4..=4), b"h"[..]);
+    assert_eq!(ring.copy_range(3..3), b""[..]);
+    assert_eq!(ring.len(), 6);
+
+    assert_eq!(ring.make_contiguous(), b"defghi");
+    assert_eq!(ring.as_slices(), (&b"defghi"[..], &b""[..]));
+    assert_eq!(ring.peek_u32(), Some(u32::from_be_bytes(*b"defg")));
+
+    ring.clear();
+    assert!(ring.is_empty());
+    assert_eq!(ring.remaining_mut(), 6);
+
+    let mut empty = RingBuf::with_capacity(0);
+    assert!(empty.is_full());
+    assert_eq!(empty.make_contiguous(), b"");
+    assert_eq!(spare_lens(&mut empty), [0usize; 0]);
+    empty.advance(0);
+}
@@ -0,0 +1,122 @@
+use crate::bytes::{
+    Bytes, BytesMut, find_any, find_byte, find_subslice, rfind_any, rfind_byte, rfind_subslice,
+};
+
+fn slices(parts: &[Bytes]) -> Vec<&[u8]> {
+    parts.iter().map(Bytes::as_slice).collect()
+}
+
+fn haystack(len: usize) -> Vec<u8> {
+    (0..len).map(|i| b'a' + (i % 7) as u8).collect()
+}
+
+#[test]
+fn test_find_byte() {
+    // cover the word loop and the remainder at every position
+    for len in 0..40 {
+        for at in 0..len {
+            let mut buf = vec![b'.'; len];
+            buf[at] = b'x';
+            assert_eq!(find_byte(&buf, b'x'), Some(at));
+            assert_eq!(rfind_byte(&buf, b'x'), Some(at));
+            assert_eq!(find_any(&buf, b"xy"), Some(at));
+            assert_eq!(rfind_any(&buf, b"yzx"), Some(at));
+            assert_eq!(find_any(&buf, b"wxyz"), Some(at));
+            assert_eq!(rfind_any(&buf, b"wxyz"), Some(at));
+        }
+        assert_eq!(find_byte(&vec![b'.'; len], b'x'), None);
+        assert_eq!(rfind_byte(&vec![b'.'; len], b'x'), None);
+    }
+
+    let buf = haystack(100);
+    for needle in 0..=255 {
+        assert_eq!(find_byte(&buf, needle), buf.iter().position(|&b| b == needle));
+        assert_eq!(rfind_byte(&buf, needle), buf.iter().rposition(|&b| b == needle));
+    }
+
+    // bytes with the high bit set
+    assert_eq!(find_byte(&[0xff, 0x80, 0x7f, 0, 1, 0x80, 0x81, 0xfe, 0x80], 0x80), Some(1));
+    assert_eq!(rfind_byte(&[0xff, 0x80, 0x7f, 0, 1, 0x80, 0x81, 0xfe, 0x80], 0x80), Some(8));
+    assert_eq!(find_byte(&[0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x00], 0x00), Some(7));
+
+    assert_eq!(find_any(b"abc", b""), None);
+    assert_eq!(rfind_any(b"abc", b""), None);
+}
+
+#[test]
+fn test_find_subslice() {
+    let buf = haystack(64);
+    for start in 0..buf.len() {
+        for len in 1..6.min(buf.len() - start) {
+            let needle = &buf[start..start + len];
+            let expected = buf.windows(len).position(|w| w == needle);
+            let rexpected = buf.windows(len).rposition(|w| w == needle);
+            assert_eq!(find_subslice(&buf, needle), expected);
+            assert_eq!(rfind_subslice(&buf, needle), rexpected);
+        }
+    }
+
+    assert_eq!(find_subslice(b"aab", b"ab"), Some(1));
+    assert_eq!(rfind_subslice(b"abb", b"ab"), Some(0));
+    assert_eq!(find_subslice(b"ab", b"abc"), None);
+    assert_eq!(rfind_subslice(b"ab", b"abc"), None);
+    assert_eq!(find_subslice(b"ab", b""), Some(0));
+    assert_eq!(rfind_subslice(b"ab", b""), Some(2));
+}
+
+#[test]
+fn test_bytes_split() {
+    let bytes = Bytes::copy_from_slice(b"a,,b,c");
+    let start = bytes.as_ptr().addr();
+
+    let parts: Vec<Bytes> = bytes.split_byte(b',').collect();
+    assert_eq!(slices(&parts), [&b"a"[..], b"", b"b", b"c"]);
+    assert_eq!(parts[3].as_ptr().addr(), start + 5);
+
+    let parts: Vec<Bytes> = bytes.splitn_byte(2, b',').collect();
+    assert_eq!(slices(&parts), [&b"a"[..], b",b,c"]);
+    assert_eq!(bytes.splitn_byte(0, b',').count(), 0);
+    assert_eq!(bytes.splitn_byte(9, b',').count(), 4);
+
+    let parts: Vec<Bytes> = bytes.split_str(b",,").collect();
+    assert_eq!(slices(&parts), [&b"a"[..], b"b,c"]);
+
+    let empty = Bytes::new();
+    assert_eq!(slices(&empty.split_byte(b',').collect::<Vec<_>>()), [&b""[..]]);
+    assert_eq!(empty.lines().count(), 0);
+
+    // slice methods are still reachable via `Deref`
+    assert_eq!(bytes.split(|&b| b == b',').count(), 4);
+    assert_eq!(bytes.splitn(2, |&b| b == b',').count(), 2);
+}
+
+#[test]
+fn test_bytes_lines() {
+    let bytes = Bytes::copy_from_slice(b"host: a\r\n\naccept: */*\r\nlast");
+    let lines: Vec<Bytes> = bytes.lines().collect();
+    assert_eq!(slices(&lines), [&b"host: a"[..], b"", b"accept: */*", b"last"]);
+
+    let bytes = Bytes::copy_from_slice(b"\r\r\n\n");
+    let lines: Vec<Bytes> = bytes.lines().collect();
+    assert_eq!(slices(&lines), [&b"\r"[..], b""]);
+}
+
+#[test]
+fn test_split_to_delimiter() {
+    let mut bufm = BytesMut::copy_from_slice(b"GET / HTTP/1.1\r\nhost: a\r\n\r\nbody");
+    let ptr = bufm.as_ptr();
+
+    let head = bufm.split_to_delimiter(b"\r\n\r\n").unwrap();
+    assert_eq!(head.as_ptr(), ptr);
+    assert_eq!(&head[..], b"GET / HTTP/1.1\r\nhost: a\r\n\r\n");
+    assert_eq!(&bufm[..], b"body");
+
+    assert!(bufm.split_to_delimiter(b"\r\n").is_none());
+    assert_eq!(&bufm[..], b"body");
+}
+
+#[test]
+#[should_panic]
+fn test_split_to_delimiter_empty() {
+    BytesMut::copy_from_slice(b"body").split_to_delimiter(b"");
+}
@@ -0,0 +1,128 @@
+use crate::bytes::error::VarintError;
+use crate::bytes::{Buf, BufList, BufMut, Bytes, varint_len};
+
+use super::test_buf::Segments;
+
+const UNSIGNED: &[u64] = &[
+    0, 1, 127, 128, 300, 16383, 16384, 1 << 35, (1 << 56) - 1, 1 << 63, u64::MAX,
+];
+
+const SIGNED: &[i64] = &[0, -1, 1, -2, 2, -64, 64, i32::MIN as i64, i64::MIN, i64::MAX];
+
+/// Returns a `BufList` of every byte as a separate segment.
+fn split_bytes(bytes: &[u8]) -> BufList {
+    bytes.iter().map(|b| Bytes::copy_from_slice(std::slice::from_ref(b))).collect()
+}
+
+#[test]
+fn test_varint_roundtrip() {
+    let mut buf = vec![];
+    for &n in UNSIGNED {
+        let len = buf.len();
+        buf.put_uvarint(n);
+        assert_eq!(buf.len() - len, varint_len(n), "{n}");
+    }
+    for &n in SIGNED {
+        buf.put_ivarint(n);
+    }
+
+    let mut contiguous = &buf[..];
+    let mut segmented = split_bytes(&buf);
+    for &n in UNSIGNED {
+        assert_eq!(contiguous.get_uvarint(), n);
+        assert_eq!(segmented.get_uvarint(), n);
+    }
+    for &n in SIGNED {
+        assert_eq!(contiguous.get_ivarint(), n);
+        assert_eq!(segmented.get_ivarint(), n);
+    }
+    assert!(!contiguous.has_remaining());
+    assert!(!segmented.has_remaining());
+}
+
+#[test]
+fn test_varint_encoding() {
+    let mut buf = vec![];
+    buf.put_ivarint(0);
+    buf.put_ivarint(-1);
+    buf.put_ivarint(1);
+    buf.put_ivarint(-2);
+    buf.put_ivarint(i64::MIN);
+    assert_eq!(buf, b"\x00\x01\x02\x03\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01");
+}
+
+#[test]
+fn test_varint_incomplete() {
+    let mut buf = &b"\xff\xff"[..];
+    assert_eq!(buf.try_get_uvarint(), None);
+    assert_eq!(buf.try_get_ivarint(), None);
+    assert_eq!(buf.remaining(), 2);
+
+    let mut buf = split_bytes(b"\xac\x02\x80\x80");
+    assert_eq!(buf.try_get_uvarint(), Some(300));
+    assert_eq!(buf.try_get_uvarint(), None);
+    assert_eq!(buf.remaining(), 2);
+
+    let mut buf = Bytes::new();
+    assert_eq!(buf.try_get_uvarint(), None);
+    assert_eq!(buf.checked_get_uvarint(), Err(VarintError::Incomplete));
+
+    let mut buf = split_bytes(b"\xff\xff");
+    assert_eq!(buf.checked_get_uvarint(), Err(VarintError::Incomplete));
+    assert_eq!(buf.checked_get_ivarint(), Err(VarintError::Incomplete));
+    assert_eq!(buf.remaining(), 2);
+}
+
+#[test]
+fn test_varint_segments() {
+    // the value spans segments of a `Buf` without `peek_at` override
+    let mut buf = Segments { segments: vec![b"\xac", b"\x02\xff", b"\x01"], vectored: true };
+    assert_eq!(buf.checked_get_uvarint(), Ok(300));
+    assert_eq!(buf.checked_get_uvarint(), Ok(255));
+    assert!(!buf.has_remaining());
+
+    let mut buf = Segments { segments: vec![b"\xac", b"\x82"], vectored: true };
+    assert_eq!(buf.checked_get_uvarint(), Err(VarintError::Incomplete));
+    assert_eq!(buf.remaining(), 2);
+}
+
+#[test]
+fn test_varint_overflow() {
+    // the 10th byte holds more than the last bit
+    let encoded = b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x02";
+    assert_eq!((&encoded[..]).try_get_uvarint(), None);
+    assert_eq!(split_bytes(encoded).try_get_uvarint(), None);
+
+    // more than 10 bytes
+    let encoded = b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x00";
+    assert_eq!((&encoded[..]).try_get_uvarint(), None);
+    assert_eq!(split_bytes(encoded).try_get_uvarint(), None);
+
+    // overflow is distinguished from incomplete, and reported before the end of input
+    for encoded in [&b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x02"[..], &[0x80; 10]] {
+        let mut contiguous = encoded;
+        assert_eq!(contiguous.checked_get_uvarint(), Err(VarintError::Overflow));
+        assert_eq!(contiguous.checked_get_ivarint(), Err(VarintError::Overflow));
+        assert_eq!(contiguous.remaining(), 10);
+
+        let mut segmented = split_bytes(encoded);
+        assert_eq!(segmented.checked_get_uvarint(), Err(VarintError::Overflow));
+        assert_eq!(segmented.remaining(), 10);
+    }
+
+    let err = std::io::Error::from(VarintError::Overflow);
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+#[should_panic]
+fn test_varint_incomplete_panic() {
+    (&b"\x80"[..]).get_uvarint();
+}
+
+#[test]
+#[should_panic]
+fn test_varint_segments_not_covered() {
+    let mut buf = Segments { segments: vec![b"\xac", b"\x02"], vectored: false };
+    let _ = buf.checked_get_uvarint();
+}
@@ -0,0 +1,143 @@
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+use crate::bytes::{Bytes, BytesMut};
+
+#[test]
+fn test_weak_static() {
+    let bytes = Bytes::from_static(b"Hello World!");
+    let weak = bytes.downgrade();
+    drop(bytes);
+
+    // static buffer is never released
+    assert_eq!(weak.upgrade().unwrap(), b"Hello World!"[..]);
+    assert_eq!(weak.clone().upgrade().unwrap(), b"Hello World!"[..]);
+}
+
+#[test]
+fn test_weak_unpromoted() {
+    let bytes = Bytes::from(vec![4; 8]);
+    bytes.assert_unpromoted();
+    assert!(bytes.is_unique());
+
+    let weak = bytes.downgrade();
+    bytes.assert_promoted();
+    assert!(!bytes.is_unique());
+
+    let upgraded = weak.upgrade().unwrap();
+    assert_eq!(upgraded.as_ptr(), bytes.as_ptr());
+    drop(upgraded);
+
+    // the weak handle does not keep the buffer alive
+    drop(bytes);
+    assert!(weak.upgrade().is_none());
+    assert!(weak.clone().upgrade().is_none());
+}
+
+#[test]
+fn test_weak_promoted() {
+    let mut bytes = Bytes::copy_from_slice(b"Hello World!");
+    let hello = bytes.split_to(5);
+    let weak = hello.downgrade();
+    let weak2 = weak.clone();
+    drop(weak);
+
+    // `bytes` keeps the allocation alive
+    drop(hello);
+    assert_eq!(weak2.upgrade().unwrap(), b"Hello"[..]);
+
+    // the buffer is reused despite the weak handle
+    assert!(!bytes.is_unique());
+    let ptr = bytes.as_ptr();
+    let vec = bytes.into_vec();
+    assert_eq!(vec.as_ptr(), ptr.wrapping_sub(5));
+    assert_eq!(vec, b" World!");
+    assert!(weak2.upgrade().is_none());
+}
+
+#[test]
+fn test_weak_blocks_reclaim() {
+    let mut bufm = BytesMut::with_capacity(16);
+    bufm.extend_from_slice(b"Hello World!");
+    let ptr = bufm.as_ptr();
+
+    let hello = bufm.split_to(6).freeze();
+    let weak = hello.downgrade();
+
+    // `bufm` keeps the allocation alive, so the head region must not be reclaimed
+    drop(hello);
+    assert!(!bufm.try_reclaim(10));
+    assert_eq!(weak.upgrade().unwrap(), b"Hello "[..]);
+    drop(weak);
+
+    assert!(bufm.try_reclaim(10));
+    assert_eq!(bufm.as_ptr(), ptr);
+    assert_eq!(&bufm[..], b"World!");
+}
+
+#[test]
+fn test_weak_concurrent_upgrade() {
+    const THREADS: usize = 8;
+
+    for _ in 0..16 {
+        let bytes = Bytes::from(vec![7; 64]);
+        let weak = bytes.downgrade();
+        let barrier = Arc::new(Barrier::new(THREADS + 1));
+
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let weak = weak.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    for _ in 0..100 {
+                        match weak.upgrade() {
+                            Some(bytes) => assert_eq!(bytes, [7; 64][..]),
+                            None => return,
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        barrier.wait();
+        drop(bytes);
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert!(weak.upgrade().is_none());
+    }
+}
+
+#[test]
+fn test_weak_concurrent_downgrade() {
+    const THREADS: usize = 8;
+
+    for _ in 0..16 {
+        // unpromoted, downgrading concurrently race on the promotion
+        let bytes = Arc::new(Bytes::from(vec![7; 64]));
+        let barrier = Arc::new(Barrier::new(THREADS));
+
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let bytes = bytes.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    bytes.downgrade()
+                })
+            })
+            .collect();
+
+        let weaks: Vec<_> = handles.into_iter().map(|e| e.join().unwrap()).collect();
+        for weak in &weaks {
+            assert_eq!(weak.upgrade().unwrap().as_ptr(), bytes.as_ptr());
+        }
+
+        drop(bytes);
+        for weak in &weaks {
+            assert!(weak.upgrade().is_none());
+        }
+    }
+}
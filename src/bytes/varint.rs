@@ -0,0 +1,103 @@
+//! LEB128 variable-length integer encoding.
+
+/// The maximum encoded length of `u64`.
+pub(crate) const MAX_LEN: usize = 10;
+
+/// Returns the number of bytes `n` takes when encoded as unsigned varint.
+///
+/// # Examples
+///
+/// ```
+/// use tcio::bytes::varint_len;
+///
+/// assert_eq!(varint_len(0), 1);
+/// assert_eq!(varint_len(127), 1);
+/// assert_eq!(varint_len(128), 2);
+/// assert_eq!(varint_len(u64::MAX), 10);
+/// ```
+#[inline]
+pub const fn varint_len(n: u64) -> usize {
+    // each byte holds 7 bits, zero still takes a byte
+    let bits = u64::BITS - (n | 1).leading_zeros();
+    bits.div_ceil(7) as usize
+}
+
+/// Encode `n` into `buf`, returns the encoded length.
+#[inline]
+pub(crate) fn encode(mut n: u64, buf: &mut [u8; MAX_LEN]) -> usize {
+    let mut i = 0;
+    while n >= 0x80 {
+        buf[i] = n as u8 | 0x80;
+        n >>= 7;
+        i += 1;
+    }
+    buf[i] = n as u8;
+    i + 1
+}
+
+/// Decode unsigned varint from the start of `buf`, returns the value and the encoded length.
+#[inline]
+pub(crate) fn decode(buf: &[u8]) -> Result<(u64, usize), VarintError> {
+    let mut n = 0u64;
+    for (i, &b) in buf.iter().take(MAX_LEN).enumerate() {
+        // the 10th byte can only hold the last bit
+        if i == MAX_LEN - 1 && b > 1 {
+            return Err(VarintError::Overflow);
+        }
+        n |= ((b & 0x7f) as u64) << (i * 7);
+        if b < 0x80 {
+            return Ok((n, i + 1));
+        }
+    }
+    Err(VarintError::Incomplete)
+}
+
+/// Map signed integer to unsigned, so that small magnitude values have short encoding.
+#[inline]
+pub(crate) const fn zigzag_encode(n: i64) -> u64 {
+    ((n << 1) ^ (n >> 63)) as u64
+}
+
+#[inline]
+pub(crate) const fn zigzag_decode(n: u64) -> i64 {
+    ((n >> 1) as i64) ^ -((n & 1) as i64)
+}
+
+// ===== Error =====
+
+/// An error returned when decoding varint from a [`Buf`].
+///
+/// The buffer is not advanced when this error is returned.
+///
+/// [`Buf`]: crate::bytes::Buf
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarintError {
+    /// The remaining bytes ends before the last byte of the varint, parsing can be retried after
+    /// more bytes are read.
+    Incomplete,
+    /// The varint overflows `u64`, either the 10th byte holds more than the last bit, or it is
+    /// longer than 10 bytes. Retrying will never succeed.
+    Overflow,
+}
+
+impl std::error::Error for VarintError { }
+
+impl std::fmt::Display for VarintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Incomplete => f.write_str("incomplete varint"),
+            Self::Overflow => f.write_str("varint overflows 64 bits"),
+        }
+    }
+}
+
+impl From<VarintError> for std::io::Error {
+    #[inline]
+    fn from(value: VarintError) -> Self {
+        let kind = match value {
+            VarintError::Incomplete => std::io::ErrorKind::UnexpectedEof,
+            VarintError::Overflow => std::io::ErrorKind::InvalidData,
+        };
+        std::io::Error::new(kind, value)
+    }
+}
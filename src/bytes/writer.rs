@@ -0,0 +1,49 @@
+use std::{cmp, io};
+
+use crate::bytes::BufMut;
+
+/// A [`BufMut`] adapter which implements [`io::Write`].
+///
+/// This struct is generally created by calling `writer()` on `BufMut`. See documentation of
+/// [`writer()`][BufMut::writer] for more details.
+#[derive(Debug)]
+pub struct Writer<B> {
+    buf: B,
+}
+
+impl<B> Writer<B> {
+    pub(crate) fn new(buf: B) -> Self {
+        Self { buf }
+    }
+
+    /// Gets a reference to the underlying `BufMut`.
+    #[inline]
+    pub fn get_ref(&self) -> &B {
+        &self.buf
+    }
+
+    /// Gets a mutable reference to the underlying `BufMut`.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.buf
+    }
+
+    /// Consumes this `Writer`, returns the underlying value.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+}
+
+impl<B: BufMut> io::Write for Writer<B> {
+    fn write(&mut self, src: &[u8]) -> io::Result<usize> {
+        let len = cmp::min(self.buf.remaining_mut(), src.len());
+        self.buf.put_slice(&src[..len]);
+        Ok(len)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use std::io;
+
+use super::{Decoder, Encoder};
+use crate::bytes::{Buf, BufMut, Bytes, BytesMut};
+
+/// A codec for raw bytes frames.
+///
+/// By default, all the buffered bytes is decoded as a single frame. Use [`fixed`] to decode
+/// frames of fixed size.
+///
+/// # Examples
+///
+/// ```
+/// use tcio::bytes::BytesMut;
+/// use tcio::codec::{BytesCodec, Decoder};
+///
+/// let mut codec = BytesCodec::fixed(4);
+/// let mut buf = BytesMut::from(&b"HelloWorld"[..]);
+///
+/// assert_eq!(codec.decode(&mut buf).unwrap().unwrap(), b"Hell"[..]);
+/// assert_eq!(codec.decode(&mut buf).unwrap().unwrap(), b"oWor"[..]);
+/// assert!(codec.decode(&mut buf).unwrap().is_none());
+/// ```
+///
+/// [`fixed`]: BytesCodec::fixed
+#[derive(Debug, Clone, Default)]
+pub struct BytesCodec {
+    frame_size: Option<usize>,
+}
+
+impl BytesCodec {
+    /// Creates new [`BytesCodec`] which decode all the buffered bytes.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { frame_size: None }
+    }
+
+    /// Creates new [`BytesCodec`] which decode frames of exactly `frame_size` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frame_size` is `0`.
+    #[inline]
+    pub const fn fixed(frame_size: usize) -> Self {
+        assert!(frame_size != 0, "frame size cannot be zero");
+        Self { frame_size: Some(frame_size) }
+    }
+}
+
+impl Decoder for BytesCodec {
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<Bytes>> {
+        match self.frame_size {
+            None if src.is_empty() => Ok(None),
+            None => Ok(Some(src.split().freeze())),
+            Some(size) if src.len() < size => {
+                src.reserve(size - src.len());
+                Ok(None)
+            }
+            Some(size) => Ok(Some(src.split_to(size).freeze())),
+        }
+    }
+}
+
+impl<B: Buf> Encoder<B> for BytesCodec {
+    type Error = io::Error;
+
+    #[inline]
+    fn encode(&mut self, item: B, dst: &mut BytesMut) -> io::Result<()> {
+        dst.put(item);
+        Ok(())
+    }
+}
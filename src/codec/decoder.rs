@@ -0,0 +1,47 @@
+use std::io;
+
+use crate::bytes::BytesMut;
+
+/// Decode frames from a stream of bytes.
+///
+/// Decoding follows the "Message" data format, a single frame can be stored entirely in memory,
+/// and when more bytes is required, decoding is retried from the beginning of the frame.
+pub trait Decoder {
+    /// The type of decoded frames.
+    type Item;
+
+    /// The type of decoding errors.
+    ///
+    /// `Framed` requires that io errors can be converted into this error type.
+    type Error: From<io::Error>;
+
+    /// Attempt to decode a frame from the provided buffer.
+    ///
+    /// If the buffer contains a complete frame, the frame bytes should be removed from `src`, and
+    /// returns `Ok(Some(frame))`.
+    ///
+    /// If the buffer does not contain a complete frame, returns `Ok(None)`. More bytes will be
+    /// appended to `src` before this method is called again. Implementor may reserve capacity in
+    /// `src` for the rest of the frame.
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error>;
+
+    /// Attempt to decode a frame after the underlying io reached EOF.
+    ///
+    /// This method is called repeatedly until it returns `Ok(None)` or an error.
+    ///
+    /// The default implementation calls [`decode`], and returns an error if the buffer contains
+    /// incomplete frame.
+    ///
+    /// [`decode`]: Decoder::decode
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(src)? {
+            Some(frame) => Ok(Some(frame)),
+            None if src.is_empty() => Ok(None),
+            None => Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "bytes remaining on stream",
+            )
+            .into()),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use std::io;
+
+use crate::bytes::BytesMut;
+
+/// Encode frames into a stream of bytes.
+pub trait Encoder<Item> {
+    /// The type of encoding errors.
+    ///
+    /// `Framed` requires that io errors can be converted into this error type.
+    type Error: From<io::Error>;
+
+    /// Encode a frame into the provided buffer.
+    ///
+    /// Encoded bytes should be appended to `dst`.
+    fn encode(&mut self, item: Item, dst: &mut BytesMut) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,219 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+
+use super::{Decoder, Encoder};
+use crate::bytes::BytesMut;
+use crate::io::{AsyncRead, AsyncWrite};
+
+const INITIAL_CAPACITY: usize = 8 * 1024;
+
+/// Minimum spare capacity of the read buffer before reading from the io.
+const MIN_READ_SIZE: usize = 1024;
+
+/// Write buffer length where sending frame waits until the buffer is written.
+const BACKPRESSURE_BOUNDARY: usize = 8 * 1024;
+
+/// A transport of frames over an io, using a [`Decoder`] and [`Encoder`].
+///
+/// Reading frames is done via [`poll_next_frame`]. Sending frames is done via
+/// [`poll_send_frame`], which only encodes the frame into the write buffer, call [`poll_flush`]
+/// to ensure that the frames is written to the io.
+///
+/// # Examples
+///
+/// ```
+/// # use std::task::{Context, Poll, Waker};
+/// use tcio::bytes::BytesMut;
+/// use tcio::codec::{Decoder, Framed};
+///
+/// /// Single byte frames.
+/// struct Octet;
+///
+/// impl Decoder for Octet {
+///     type Item = u8;
+///     type Error = std::io::Error;
+///
+///     fn decode(&mut self, src: &mut BytesMut) -> Result<Option<u8>, Self::Error> {
+///         Ok(src.split_to(src.len().min(1)).first().copied())
+///     }
+/// }
+///
+/// let mut cx = Context::from_waker(Waker::noop());
+/// let mut framed = Framed::new(&b"ab"[..], Octet);
+///
+/// assert!(matches!(framed.poll_next_frame(&mut cx), Poll::Ready(Some(Ok(b'a')))));
+/// assert!(matches!(framed.poll_next_frame(&mut cx), Poll::Ready(Some(Ok(b'b')))));
+/// assert!(matches!(framed.poll_next_frame(&mut cx), Poll::Ready(None)));
+/// ```
+///
+/// [`poll_next_frame`]: Framed::poll_next_frame
+/// [`poll_send_frame`]: Framed::poll_send_frame
+/// [`poll_flush`]: Framed::poll_flush
+#[derive(Debug)]
+pub struct Framed<IO, C> {
+    io: IO,
+    codec: C,
+    read_buf: BytesMut,
+    write_buf: BytesMut,
+    eof: bool,
+    /// [`Decoder::decode_eof`] returned `None` or an error, no more frame is yielded.
+    terminated: bool,
+}
+
+impl<IO, C> Framed<IO, C> {
+    /// Creates new [`Framed`].
+    #[inline]
+    pub fn new(io: IO, codec: C) -> Self {
+        Self {
+            io,
+            codec,
+            read_buf: BytesMut::with_capacity(INITIAL_CAPACITY),
+            write_buf: BytesMut::new(),
+            eof: false,
+            terminated: false,
+        }
+    }
+
+    /// Returns a shared reference to the underlying io.
+    #[inline]
+    pub fn inner(&self) -> &IO {
+        &self.io
+    }
+
+    /// Returns a mutable reference to the underlying io.
+    ///
+    /// Note that reading or writing directly to the underlying io may corrupt the stream.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut IO {
+        &mut self.io
+    }
+
+    /// Returns a shared reference to the codec.
+    #[inline]
+    pub fn codec(&self) -> &C {
+        &self.codec
+    }
+
+    /// Returns a mutable reference to the codec.
+    #[inline]
+    pub fn codec_mut(&mut self) -> &mut C {
+        &mut self.codec
+    }
+
+    /// Returns a shared reference to the read buffer.
+    #[inline]
+    pub fn read_buffer(&self) -> &BytesMut {
+        &self.read_buf
+    }
+
+    /// Returns a mutable reference to the read buffer.
+    #[inline]
+    pub fn read_buffer_mut(&mut self) -> &mut BytesMut {
+        &mut self.read_buf
+    }
+
+    /// Returns a shared reference to the write buffer.
+    #[inline]
+    pub fn write_buffer(&self) -> &BytesMut {
+        &self.write_buf
+    }
+
+    /// Returns a mutable reference to the write buffer.
+    #[inline]
+    pub fn write_buffer_mut(&mut self) -> &mut BytesMut {
+        &mut self.write_buf
+    }
+
+    /// Consumes this `Framed`, returns the underlying io and the codec.
+    ///
+    /// Note that any buffered bytes are lost.
+    #[inline]
+    pub fn into_parts(self) -> (IO, C) {
+        (self.io, self.codec)
+    }
+}
+
+impl<IO: AsyncRead + Unpin, C: Decoder> Framed<IO, C> {
+    /// Attempt to read the next frame.
+    ///
+    /// Returns `Poll::Ready(None)` when the underlying io reached EOF and there is no more frame
+    /// to decode.
+    ///
+    /// After EOF, once [`Decoder::decode_eof`] returns `None` or an error, the stream is
+    /// terminated, subsequent calls return `Poll::Ready(None)` without calling the codec.
+    pub fn poll_next_frame(
+        &mut self,
+        cx: &mut Context,
+    ) -> Poll<Option<Result<C::Item, C::Error>>> {
+        loop {
+            if self.terminated {
+                return Poll::Ready(None);
+            }
+
+            if self.eof {
+                let result = self.codec.decode_eof(&mut self.read_buf).transpose();
+                self.terminated = !matches!(result, Some(Ok(_)));
+                return Poll::Ready(result);
+            }
+
+            if let Some(frame) = self.codec.decode(&mut self.read_buf)? {
+                return Poll::Ready(Some(Ok(frame)));
+            }
+
+            // reuse the consumed read buffer before allocating
+            if !self.read_buf.try_reclaim(MIN_READ_SIZE) {
+                self.read_buf.reserve(INITIAL_CAPACITY);
+            }
+
+            let read = ready!(Pin::new(&mut self.io).poll_read(&mut self.read_buf, cx))?;
+            if read == 0 {
+                self.eof = true;
+            }
+        }
+    }
+}
+
+impl<IO: AsyncWrite + Unpin, C> Framed<IO, C> {
+    /// Attempt to encode a frame into the write buffer.
+    ///
+    /// The frame is taken out of `item` once it is encoded, so that it is not encoded twice when
+    /// the method is polled again after `Poll::Pending`.
+    ///
+    /// If the write buffer is full, it is written to the underlying io before encoding the frame.
+    /// Note that this does not write the encoded frame, call [`poll_flush`] to write all the
+    /// encoded frames.
+    ///
+    /// [`poll_flush`]: Framed::poll_flush
+    pub fn poll_send_frame<I>(
+        &mut self,
+        item: &mut Option<I>,
+        cx: &mut Context,
+    ) -> Poll<Result<(), C::Error>>
+    where
+        C: Encoder<I>,
+    {
+        if self.write_buf.len() >= BACKPRESSURE_BOUNDARY {
+            ready!(self.poll_write_buf(cx))?;
+        }
+        if let Some(item) = item.take() {
+            self.codec.encode(item, &mut self.write_buf)?;
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    /// Attempt to write all the encoded frames and flush the underlying io.
+    ///
+    /// Unlike [`poll_send_frame`], this method does not involve the codec, so it returns
+    /// [`io::Error`] directly. Note that [`Encoder::Error`] is always convertible from it.
+    ///
+    /// [`poll_send_frame`]: Framed::poll_send_frame
+    pub fn poll_flush(&mut self, cx: &mut Context) -> Poll<io::Result<()>> {
+        ready!(self.poll_write_buf(cx))?;
+        Pin::new(&mut self.io).poll_flush(cx)
+    }
+
+    fn poll_write_buf(&mut self, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.io).poll_write_all_buf(&mut self.write_buf, cx)
+    }
+}
@@ -0,0 +1,211 @@
+use std::io;
+
+use super::{Decoder, Encoder};
+use crate::bytes::{Buf, BufMut, Bytes, BytesMut};
+
+const DEFAULT_MAX_FRAME_LENGTH: usize = 8 * 1024 * 1024;
+
+/// A codec for frames delimited by a length field.
+///
+/// The frame layout is as follows:
+///
+/// ```text
+/// [ offset bytes ][ length field ][ payload ]
+/// ```
+///
+/// The length of the payload is the length field value plus the [`length_adjustment`]. For
+/// example, a protocol where the length field also counts itself is decoded with adjustment of
+/// negative length field width.
+///
+/// By default, the length field is 4 bytes big endian, with no offset nor adjustment, and the
+/// decoded frame does not include the header.
+///
+/// # Examples
+///
+/// ```
+/// use tcio::bytes::BytesMut;
+/// use tcio::codec::{Decoder, LengthDelimitedCodec};
+///
+/// let mut codec = LengthDelimitedCodec::new()
+///     .length_field_width(2)
+///     .little_endian();
+///
+/// let mut buf = BytesMut::from(&b"\x05\x00Hello\x06\x00World!"[..]);
+///
+/// assert_eq!(codec.decode(&mut buf).unwrap().unwrap(), b"Hello"[..]);
+/// assert_eq!(codec.decode(&mut buf).unwrap().unwrap(), b"World!"[..]);
+/// assert!(codec.decode(&mut buf).unwrap().is_none());
+/// ```
+///
+/// [`length_adjustment`]: LengthDelimitedCodec::length_adjustment
+#[derive(Debug, Clone)]
+pub struct LengthDelimitedCodec {
+    width: usize,
+    little_endian: bool,
+    offset: usize,
+    adjustment: isize,
+    max_frame_length: usize,
+    include_header: bool,
+}
+
+impl LengthDelimitedCodec {
+    /// Creates new [`LengthDelimitedCodec`] with default configuration.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            width: 4,
+            little_endian: false,
+            offset: 0,
+            adjustment: 0,
+            max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+            include_header: false,
+        }
+    }
+
+    /// Sets the length field width in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is not in range of `1..=8`.
+    #[inline]
+    pub const fn length_field_width(mut self, width: usize) -> Self {
+        assert!(width >= 1 && width <= 8, "length field width must be in range of 1..=8");
+        self.width = width;
+        self
+    }
+
+    /// Read and write the length field in big endian, this is the default.
+    #[inline]
+    pub const fn big_endian(mut self) -> Self {
+        self.little_endian = false;
+        self
+    }
+
+    /// Read and write the length field in little endian.
+    #[inline]
+    pub const fn little_endian(mut self) -> Self {
+        self.little_endian = true;
+        self
+    }
+
+    /// Sets the number of bytes preceding the length field.
+    ///
+    /// Note that the encoder does not write the offset bytes.
+    #[inline]
+    pub const fn length_field_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the value added to the length field value to get the payload length.
+    #[inline]
+    pub const fn length_adjustment(mut self, adjustment: isize) -> Self {
+        self.adjustment = adjustment;
+        self
+    }
+
+    /// Sets the maximum payload length, the default is 8 MiB.
+    ///
+    /// Frame with larger payload results in [`io::ErrorKind::InvalidData`] error.
+    #[inline]
+    pub const fn max_frame_length(mut self, max: usize) -> Self {
+        self.max_frame_length = max;
+        self
+    }
+
+    /// Sets whether the decoded frame includes the offset bytes and the length field.
+    #[inline]
+    pub const fn include_header(mut self, include: bool) -> Self {
+        self.include_header = include;
+        self
+    }
+
+    fn header_len(&self) -> usize {
+        self.offset + self.width
+    }
+
+    fn get_length_field(&self, field: &[u8]) -> u64 {
+        let mut bytes = [0u8; 8];
+        if self.little_endian {
+            bytes[..self.width].copy_from_slice(field);
+            (&bytes[..]).get_u64_le()
+        } else {
+            bytes[8 - self.width..].copy_from_slice(field);
+            (&bytes[..]).get_u64()
+        }
+    }
+}
+
+impl Default for LengthDelimitedCodec {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decoder for LengthDelimitedCodec {
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<Bytes>> {
+        let header_len = self.header_len();
+        if src.len() < header_len {
+            src.reserve(header_len - src.len());
+            return Ok(None);
+        }
+
+        let field = self.get_length_field(&src[self.offset..header_len]);
+        let Some(payload_len) = i128::from(field)
+            .checked_add(self.adjustment as i128)
+            .and_then(|len| usize::try_from(len).ok())
+        else {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "invalid frame length"));
+        };
+        if payload_len > self.max_frame_length {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "frame length too large"));
+        }
+
+        let Some(frame_len) = header_len.checked_add(payload_len) else {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "frame length too large"));
+        };
+        if src.len() < frame_len {
+            src.reserve(frame_len - src.len());
+            return Ok(None);
+        }
+
+        let mut frame = src.split_to(frame_len);
+        if !self.include_header {
+            frame.advance(header_len);
+        }
+        Ok(Some(frame.freeze()))
+    }
+}
+
+impl<B: Buf> Encoder<B> for LengthDelimitedCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: B, dst: &mut BytesMut) -> io::Result<()> {
+        let payload_len = item.remaining();
+        if payload_len > self.max_frame_length {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "frame length too large"));
+        }
+
+        let field = (payload_len as i128) - (self.adjustment as i128);
+        if field < 0 || field >> (self.width * 8) != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frame length does not fit the length field",
+            ));
+        }
+
+        let field = field as u64;
+        dst.reserve(self.width + payload_len);
+        if self.little_endian {
+            dst.put_slice(&field.to_le_bytes()[..self.width]);
+        } else {
+            dst.put_slice(&field.to_be_bytes()[8 - self.width..]);
+        }
+        dst.put(item);
+        Ok(())
+    }
+}
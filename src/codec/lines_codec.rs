@@ -0,0 +1,147 @@
+use std::io;
+
+use super::{Decoder, Encoder};
+use crate::bytes::{Buf, BufMut, ByteStr, BytesMut};
+
+/// A codec for frames delimited by newline.
+///
+/// Decoded lines does not include the trailing `\n` or `\r\n`. At EOF, the remaining bytes is
+/// decoded as the last line.
+///
+/// # Examples
+///
+/// ```
+/// use tcio::bytes::BytesMut;
+/// use tcio::codec::{Decoder, LinesCodec};
+///
+/// let mut codec = LinesCodec::new();
+/// let mut buf = BytesMut::from(&b"Hello\r\nWorld!"[..]);
+///
+/// assert_eq!(codec.decode(&mut buf).unwrap().unwrap().as_str(), "Hello");
+/// assert!(codec.decode(&mut buf).unwrap().is_none());
+/// assert_eq!(codec.decode_eof(&mut buf).unwrap().unwrap().as_str(), "World!");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LinesCodec {
+    /// Length of the buffer that is already searched for newline.
+    searched: usize,
+    max_length: usize,
+    /// Whether the rest of too long line is being discarded.
+    discarding: bool,
+}
+
+impl LinesCodec {
+    /// Creates new [`LinesCodec`] without maximum line length.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_max_length(usize::MAX)
+    }
+
+    /// Creates new [`LinesCodec`] with maximum line length.
+    ///
+    /// Line longer than `max_length`, excluding the newline, results in
+    /// [`io::ErrorKind::InvalidData`] error. The error is returned once, then the rest of the line
+    /// is discarded and decoding resumes from the next line.
+    #[inline]
+    pub const fn with_max_length(max_length: usize) -> Self {
+        Self {
+            searched: 0,
+            max_length,
+            discarding: false,
+        }
+    }
+
+    /// Returns the maximum line length.
+    #[inline]
+    pub const fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    fn decode_line(&self, mut line: BytesMut) -> io::Result<ByteStr> {
+        if line.last() == Some(&b'\n') {
+            line.truncate(line.len() - 1);
+        }
+        if line.last() == Some(&b'\r') {
+            line.truncate(line.len() - 1);
+        }
+        if line.len() > self.max_length {
+            return Err(line_too_long());
+        }
+        ByteStr::from_utf8(line.freeze())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, *err.utf8_error()))
+    }
+}
+
+impl Default for LinesCodec {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decoder for LinesCodec {
+    type Item = ByteStr;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<ByteStr>> {
+        if self.discarding {
+            match src.iter().position(|&b| b == b'\n') {
+                Some(n) => {
+                    src.advance(n + 1);
+                    self.discarding = false;
+                }
+                None => {
+                    src.clear();
+                    return Ok(None);
+                }
+            }
+        }
+
+        match src[self.searched..].iter().position(|&b| b == b'\n') {
+            Some(n) => {
+                let line = src.split_to(self.searched + n + 1);
+                self.searched = 0;
+                self.decode_line(line).map(Some)
+            }
+            None => {
+                self.searched = src.len();
+                // the line may ends with `\r` which is not counted
+                if src.len() > self.max_length.saturating_add(1) {
+                    // free the buffer, the rest of the line is discarded in later call
+                    src.clear();
+                    self.searched = 0;
+                    self.discarding = true;
+                    return Err(line_too_long());
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> io::Result<Option<ByteStr>> {
+        match self.decode(src)? {
+            Some(line) => Ok(Some(line)),
+            None if src.is_empty() => Ok(None),
+            None => {
+                self.searched = 0;
+                self.decode_line(src.split()).map(Some)
+            }
+        }
+    }
+}
+
+impl<T: AsRef<str>> Encoder<T> for LinesCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> io::Result<()> {
+        let line = item.as_ref();
+        dst.reserve(line.len() + 1);
+        dst.put_slice(line.as_bytes());
+        dst.put_u8(b'\n');
+        Ok(())
+    }
+}
+
+fn line_too_long() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "line too long")
+}
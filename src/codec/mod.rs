@@ -0,0 +1,33 @@
+//! Provide utilities for encoding and decoding frames.
+//!
+//! Overview:
+//!
+//! - [`Decoder`] decode frames from a [`BytesMut`] read buffer.
+//! - [`Encoder`] encode frames into a [`BytesMut`] write buffer.
+//! - [`Framed`] drive a `Decoder` and `Encoder` over an io.
+//!
+//! Built-in codecs:
+//!
+//! - [`LengthDelimitedCodec`] for frames prefixed with its length.
+//! - [`LinesCodec`] for newline delimited text.
+//! - [`BytesCodec`] for raw or fixed size frames.
+//!
+//! [`BytesMut`]: crate::bytes::BytesMut
+mod decoder;
+mod encoder;
+mod framed;
+
+mod bytes_codec;
+mod length_delimited;
+mod lines_codec;
+
+pub use decoder::Decoder;
+pub use encoder::Encoder;
+pub use framed::Framed;
+
+pub use bytes_codec::BytesCodec;
+pub use length_delimited::LengthDelimitedCodec;
+pub use lines_codec::LinesCodec;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,67 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+use crate::bytes::{BufMut, BytesMut};
+use crate::codec::Decoder;
+use crate::io::AsyncRead;
+
+mod test_framed;
+mod test_bytes_codec;
+mod test_length_delimited;
+mod test_lines_codec;
+
+fn cx() -> Context<'static> {
+    Context::from_waker(Waker::noop())
+}
+
+/// Feed `input` to the decoder one byte at a time, then decode until EOF.
+fn decode_bytewise<D: Decoder>(codec: &mut D, input: &[u8]) -> Result<Vec<D::Item>, D::Error> {
+    let mut buf = BytesMut::new();
+    let mut frames = vec![];
+    for &byte in input {
+        buf.put_u8(byte);
+        while let Some(frame) = codec.decode(&mut buf)? {
+            frames.push(frame);
+        }
+    }
+    while let Some(frame) = codec.decode_eof(&mut buf)? {
+        frames.push(frame);
+    }
+    Ok(frames)
+}
+
+/// Mock io that yields a single byte for each read, with `Poll::Pending` in between.
+struct OneByte<'a> {
+    bytes: &'a [u8],
+    pending: bool,
+}
+
+impl<'a> OneByte<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            pending: true,
+        }
+    }
+}
+
+impl AsyncRead for OneByte<'_> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        mut buf: impl BufMut,
+        _: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        let me = self.get_mut();
+        me.pending = !me.pending;
+        if !me.pending {
+            return Poll::Pending;
+        }
+        let Some((&byte, rest)) = me.bytes.split_first() else {
+            return Poll::Ready(Ok(0));
+        };
+        buf.put_u8(byte);
+        me.bytes = rest;
+        Poll::Ready(Ok(1))
+    }
+}
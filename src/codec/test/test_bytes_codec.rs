@@ -0,0 +1,32 @@
+use std::io;
+
+use super::decode_bytewise;
+use crate::bytes::{Bytes, BytesMut};
+use crate::codec::{BytesCodec, Decoder, Encoder};
+
+#[test]
+fn test_bytes_codec() {
+    let mut codec = BytesCodec::new();
+
+    let mut buf = BytesMut::from(&b"Hello"[..]);
+    assert_eq!(codec.decode(&mut buf).unwrap().unwrap(), b"Hello"[..]);
+    assert!(codec.decode(&mut buf).unwrap().is_none());
+
+    let frames = decode_bytewise(&mut codec, b"abc").unwrap();
+    assert_eq!(frames, [&b"a"[..], b"b", b"c"].map(Bytes::from_static));
+
+    codec.encode(&b"Hello"[..], &mut buf).unwrap();
+    codec.encode(Bytes::from_static(b"World!"), &mut buf).unwrap();
+    assert_eq!(&buf[..], b"HelloWorld!");
+}
+
+#[test]
+fn test_bytes_codec_fixed() {
+    let mut codec = BytesCodec::fixed(3);
+
+    let frames = decode_bytewise(&mut codec, b"abcdefghi").unwrap();
+    assert_eq!(frames, [&b"abc"[..], b"def", b"ghi"].map(Bytes::from_static));
+
+    let err = decode_bytewise(&mut codec, b"abcdefgh").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
@@ -0,0 +1,116 @@
+use std::io;
+use std::task::Poll;
+
+use super::{OneByte, cx};
+use crate::bytes::{BufMut, Bytes, BytesMut};
+use crate::codec::{Decoder, Encoder, Framed};
+
+/// Frames prefixed with a single byte length.
+struct Prefixed;
+
+impl Decoder for Prefixed {
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Bytes>, io::Error> {
+        let Some(&len) = src.first() else {
+            return Ok(None);
+        };
+        if src.len() < 1 + len as usize {
+            return Ok(None);
+        }
+        src.split_to(1);
+        Ok(Some(src.split_to(len as usize).freeze()))
+    }
+}
+
+impl Encoder<&[u8]> for Prefixed {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: &[u8], dst: &mut BytesMut) -> Result<(), io::Error> {
+        dst.put_u8(item.len() as u8);
+        dst.put_slice(item);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_framed_next_frame() {
+    let mut cx = cx();
+    let mut framed = Framed::new(OneByte::new(b"\x05Hello\x00\x06World!"), Prefixed);
+    let mut frames = vec![];
+
+    loop {
+        match framed.poll_next_frame(&mut cx) {
+            Poll::Ready(Some(frame)) => frames.push(frame.unwrap()),
+            Poll::Ready(None) => break,
+            Poll::Pending => {}
+        }
+    }
+
+    assert_eq!(frames, [&b"Hello"[..], b"", b"World!"].map(Bytes::from_static));
+}
+
+#[test]
+fn test_framed_eof_incomplete() {
+    let mut cx = cx();
+    let mut framed = Framed::new(&b"\x05Hello\x06World"[..], Prefixed);
+
+    let Poll::Ready(Some(Ok(hello))) = framed.poll_next_frame(&mut cx) else {
+        panic!("expected frame")
+    };
+    assert_eq!(&hello, &b"Hello"[..]);
+
+    let Poll::Ready(Some(Err(err))) = framed.poll_next_frame(&mut cx) else {
+        panic!("expected error")
+    };
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+    // the codec is not called again after the error
+    assert!(matches!(framed.poll_next_frame(&mut cx), Poll::Ready(None)));
+    assert!(matches!(framed.poll_next_frame(&mut cx), Poll::Ready(None)));
+    assert_eq!(&framed.read_buffer()[..], b"\x06World");
+}
+
+#[test]
+fn test_framed_reclaim_read_buffer() {
+    let mut cx = cx();
+    let input = b"\x05Hello".repeat(4096);
+    let mut framed = Framed::new(OneByte::new(&input), Prefixed);
+
+    let start = framed.read_buffer().as_ptr();
+    let end = start.wrapping_add(framed.read_buffer().capacity());
+    let mut frames = 0;
+
+    loop {
+        match framed.poll_next_frame(&mut cx) {
+            Poll::Ready(Some(frame)) => {
+                assert_eq!(&frame.unwrap(), &b"Hello"[..]);
+                frames += 1;
+            }
+            Poll::Ready(None) => break,
+            Poll::Pending => {}
+        }
+        // frames is dropped immediately, so the read buffer is never reallocated
+        let ptr = framed.read_buffer().as_ptr();
+        assert!(start <= ptr && ptr <= end);
+    }
+
+    assert_eq!(frames, 4096);
+}
+
+#[test]
+fn test_framed_send_frame() {
+    let mut cx = cx();
+    let mut framed = Framed::new(Vec::new(), Prefixed);
+
+    let mut item = Some(&b"Hello"[..]);
+    assert!(framed.poll_send_frame(&mut item, &mut cx).is_ready());
+    assert!(item.is_none());
+    assert!(framed.inner().is_empty());
+
+    assert!(framed.poll_send_frame(&mut Some(&b"World!"[..]), &mut cx).is_ready());
+    assert!(framed.poll_flush(&mut cx).is_ready());
+    assert_eq!(framed.inner(), b"\x05Hello\x06World!");
+    assert!(framed.write_buffer().is_empty());
+}
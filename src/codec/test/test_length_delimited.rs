@@ -0,0 +1,132 @@
+use std::io;
+use std::task::Poll;
+
+use super::{OneByte, cx, decode_bytewise};
+use crate::bytes::{Bytes, BytesMut};
+use crate::codec::{Encoder, Framed, LengthDelimitedCodec};
+
+const FRAMES: [&[u8]; 4] = [b"Hello", b"", b"World!", &[0xAB; 300]];
+
+fn encode_all(codec: &mut LengthDelimitedCodec) -> BytesMut {
+    let mut buf = BytesMut::new();
+    for frame in FRAMES {
+        codec.encode(frame, &mut buf).unwrap();
+    }
+    buf
+}
+
+#[test]
+fn test_length_delimited_widths() {
+    for width in 2..=8 {
+        for little_endian in [false, true] {
+            let mut codec = LengthDelimitedCodec::new().length_field_width(width);
+            if little_endian {
+                codec = codec.little_endian();
+            }
+
+            let buf = encode_all(&mut codec);
+            assert_eq!(buf.len(), FRAMES.iter().map(|f| width + f.len()).sum::<usize>());
+
+            let frames = decode_bytewise(&mut codec, &buf).unwrap();
+            assert_eq!(frames, FRAMES.map(Bytes::from_static));
+        }
+    }
+}
+
+#[test]
+fn test_length_delimited_endianness() {
+    let mut buf = BytesMut::new();
+
+    let mut codec = LengthDelimitedCodec::new().length_field_width(3);
+    codec.encode(&b"ab"[..], &mut buf).unwrap();
+    assert_eq!(&buf[..], b"\x00\x00\x02ab");
+
+    buf.clear();
+    let mut codec = codec.little_endian();
+    codec.encode(&b"ab"[..], &mut buf).unwrap();
+    assert_eq!(&buf[..], b"\x02\x00\x00ab");
+}
+
+#[test]
+fn test_length_delimited_field_overflow() {
+    let mut codec = LengthDelimitedCodec::new().length_field_width(1);
+    let mut buf = BytesMut::new();
+
+    codec.encode(&[0u8; 255][..], &mut buf).unwrap();
+    let err = codec.encode(&[0u8; 256][..], &mut buf).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+    let frames = decode_bytewise(&mut codec, &buf).unwrap();
+    assert_eq!(frames.len(), 1);
+    assert_eq!(frames[0].len(), 255);
+}
+
+#[test]
+fn test_length_delimited_offset_adjustment() {
+    // 1 byte message type, followed by big endian u32 length that also counts itself,
+    // e.g. postgres protocol message
+    let input = b"Q\x00\x00\x00\x0bSELECT\x00Z\x00\x00\x00\x05I";
+    let mut codec = LengthDelimitedCodec::new()
+        .length_field_offset(1)
+        .length_adjustment(-4)
+        .include_header(true);
+
+    let frames = decode_bytewise(&mut codec, input).unwrap();
+    assert_eq!(frames, [&input[..12], &input[12..]].map(Bytes::copy_from_slice));
+
+    let mut codec = codec.include_header(false);
+    let frames = decode_bytewise(&mut codec, input).unwrap();
+    assert_eq!(frames, [&b"SELECT\x00"[..], b"I"].map(Bytes::from_static));
+}
+
+#[test]
+fn test_length_delimited_invalid_length() {
+    let mut codec = LengthDelimitedCodec::new()
+        .length_field_width(1)
+        .length_adjustment(-4);
+    let err = decode_bytewise(&mut codec, b"\x03abc").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    let mut codec = LengthDelimitedCodec::new().max_frame_length(4);
+    assert!(decode_bytewise(&mut codec, b"\x00\x00\x00\x04abcd").is_ok());
+    let err = decode_bytewise(&mut codec, b"\x00\x00\x00\x05").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    // header length plus payload length overflows
+    let mut codec = LengthDelimitedCodec::new()
+        .length_field_width(8)
+        .max_frame_length(usize::MAX);
+    let header = (usize::MAX as u64).to_be_bytes();
+    let err = decode_bytewise(&mut codec, &header).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_length_delimited_eof() {
+    let mut codec = LengthDelimitedCodec::new();
+
+    let buf = encode_all(&mut codec);
+    for len in 0..buf.len() {
+        let result = decode_bytewise(&mut codec, &buf[..len]);
+        let is_boundary = [0, 9, 13, 23, 327].contains(&len);
+        assert_eq!(result.is_ok(), is_boundary, "incomplete frame at {len}");
+    }
+}
+
+#[test]
+fn test_length_delimited_framed() {
+    let mut cx = cx();
+    let mut codec = LengthDelimitedCodec::new().length_field_width(2);
+    let buf = encode_all(&mut codec);
+
+    let mut framed = Framed::new(OneByte::new(&buf), codec);
+    let mut frames = vec![];
+    loop {
+        match framed.poll_next_frame(&mut cx) {
+            Poll::Ready(Some(frame)) => frames.push(frame.unwrap()),
+            Poll::Ready(None) => break,
+            Poll::Pending => {}
+        }
+    }
+    assert_eq!(frames, FRAMES.map(Bytes::from_static));
+}
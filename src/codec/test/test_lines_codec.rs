@@ -0,0 +1,81 @@
+use std::io;
+
+use super::decode_bytewise;
+use crate::bytes::{BufMut, ByteStr, BytesMut};
+use crate::codec::{Decoder, Encoder, LinesCodec};
+
+#[test]
+fn test_lines_codec() {
+    let mut codec = LinesCodec::new();
+    let input = b"Hello\nWorld!\r\n\n\r\nlast line";
+
+    let lines = decode_bytewise(&mut codec, input).unwrap();
+    assert_eq!(lines, ["Hello", "World!", "", "", "last line"].map(ByteStr::from_static));
+
+    let lines = decode_bytewise(&mut codec, b"trailing\n").unwrap();
+    assert_eq!(lines, ["trailing"].map(ByteStr::from_static));
+
+    let lines = decode_bytewise(&mut codec, b"cr at eof\r").unwrap();
+    assert_eq!(lines, ["cr at eof"].map(ByteStr::from_static));
+
+    assert!(decode_bytewise(&mut codec, b"").unwrap().is_empty());
+}
+
+#[test]
+fn test_lines_codec_max_length() {
+    let mut codec = LinesCodec::with_max_length(5);
+
+    let lines = decode_bytewise(&mut codec, b"Hello\r\nWorld\n").unwrap();
+    assert_eq!(lines, ["Hello", "World"].map(ByteStr::from_static));
+
+    let err = decode_bytewise(&mut LinesCodec::with_max_length(5), b"World!\n").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    // error before the newline is received
+    let err = decode_bytewise(&mut LinesCodec::with_max_length(5), b"Hello World").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_lines_codec_discard_too_long() {
+    let mut codec = LinesCodec::with_max_length(5);
+    let mut buf = BytesMut::new();
+    let mut results = vec![];
+
+    for &byte in b"Hello World\nok\n" {
+        buf.put_u8(byte);
+        loop {
+            match codec.decode(&mut buf) {
+                Ok(Some(line)) => results.push(Ok(line)),
+                Ok(None) => break,
+                Err(err) => results.push(Err(err.kind())),
+            }
+        }
+        // the too long line is not kept in the buffer
+        assert!(buf.len() <= 7);
+    }
+    assert!(codec.decode_eof(&mut buf).unwrap().is_none());
+
+    assert_eq!(results, [Err(io::ErrorKind::InvalidData), Ok(ByteStr::from_static("ok"))]);
+}
+
+#[test]
+fn test_lines_codec_utf8() {
+    let mut codec = LinesCodec::new();
+
+    let lines = decode_bytewise(&mut codec, "héllo\nwörld".as_bytes()).unwrap();
+    assert_eq!(lines, ["héllo", "wörld"].map(ByteStr::from_static));
+
+    let err = decode_bytewise(&mut codec, b"\xFF\xFE\n").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_lines_codec_encode() {
+    let mut codec = LinesCodec::new();
+    let mut buf = BytesMut::new();
+
+    codec.encode("Hello", &mut buf).unwrap();
+    codec.encode(String::from("World!"), &mut buf).unwrap();
+    assert_eq!(&buf[..], b"Hello\nWorld!\n");
+}
@@ -1,8 +1,11 @@
 use std::fmt;
 use std::io;
 use std::pin::Pin;
+use std::task::{Context, Poll};
 
+use crate::bytes::{Buf, BufMut, Bytes, UninitSlice};
 use crate::either::EitherMap;
+use crate::io::{AsyncBufRead, AsyncRead, AsyncWrite};
 
 /// Represent either type that implement the same trait.
 ///
@@ -18,6 +21,20 @@ pub enum Either<L, R> {
     Right(R),
 }
 
+impl<L, R> Either<L, R> {
+    /// Project pinned `Either` into `Either` of pinned variant.
+    pub(crate) fn project(self: Pin<&mut Self>) -> Either<Pin<&mut L>, Pin<&mut R>> {
+        // SAFETY: self is pinned
+        // no `Drop`, nor manual `Unpin` implementation.
+        unsafe {
+            match self.get_unchecked_mut() {
+                Self::Left(l) => Either::Left(Pin::new_unchecked(l)),
+                Self::Right(r) => Either::Right(Pin::new_unchecked(r)),
+            }
+        }
+    }
+}
+
 impl<L, R> From<EitherMap<L, R>> for Either<L, R> {
     #[inline]
     fn from(value: EitherMap<L, R>) -> Self {
@@ -175,3 +192,304 @@ impl<L: AsRef<str>, R: AsRef<str>> AsRef<str> for Either<L, R> {
         }
     }
 }
+
+// ===== bytes traits =====
+
+impl<L: Buf, R: Buf> Buf for Either<L, R> {
+    #[inline]
+    fn remaining(&self) -> usize {
+        match self {
+            Self::Left(l) => l.remaining(),
+            Self::Right(r) => r.remaining(),
+        }
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        match self {
+            Self::Left(l) => l.chunk(),
+            Self::Right(r) => r.chunk(),
+        }
+    }
+
+    #[inline]
+    fn advance(&mut self, cnt: usize) {
+        match self {
+            Self::Left(l) => l.advance(cnt),
+            Self::Right(r) => r.advance(cnt),
+        }
+    }
+
+    #[inline]
+    fn chunks_vectored<'a>(&'a self, dst: &mut [io::IoSlice<'a>]) -> usize {
+        match self {
+            Self::Left(l) => l.chunks_vectored(dst),
+            Self::Right(r) => r.chunks_vectored(dst),
+        }
+    }
+
+    #[inline]
+    fn has_remaining(&self) -> bool {
+        match self {
+            Self::Left(l) => l.has_remaining(),
+            Self::Right(r) => r.has_remaining(),
+        }
+    }
+
+    #[inline]
+    fn copy_to_slice(&mut self, dst: &mut [u8]) {
+        match self {
+            Self::Left(l) => l.copy_to_slice(dst),
+            Self::Right(r) => r.copy_to_slice(dst),
+        }
+    }
+
+    #[inline]
+    fn peek_at(&self, offset: usize, dst: &mut [u8]) -> bool {
+        match self {
+            Self::Left(l) => l.peek_at(offset, dst),
+            Self::Right(r) => r.peek_at(offset, dst),
+        }
+    }
+
+    #[inline]
+    fn copy_to_bytes(&mut self, len: usize) -> Bytes {
+        match self {
+            Self::Left(l) => l.copy_to_bytes(len),
+            Self::Right(r) => r.copy_to_bytes(len),
+        }
+    }
+}
+
+impl<L: BufMut, R: BufMut> BufMut for Either<L, R> {
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        match self {
+            Self::Left(l) => l.remaining_mut(),
+            Self::Right(r) => r.remaining_mut(),
+        }
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        match self {
+            Self::Left(l) => l.chunk_mut(),
+            Self::Right(r) => r.chunk_mut(),
+        }
+    }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        // SAFETY: the safety contract is forwarded to the caller
+        unsafe {
+            match self {
+                Self::Left(l) => l.advance_mut(cnt),
+                Self::Right(r) => r.advance_mut(cnt),
+            }
+        }
+    }
+
+    #[inline]
+    fn chunks_vectored_mut<'a>(&'a mut self, dst: &mut [&'a mut UninitSlice]) -> usize {
+        match self {
+            Self::Left(l) => l.chunks_vectored_mut(dst),
+            Self::Right(r) => r.chunks_vectored_mut(dst),
+        }
+    }
+
+    #[inline]
+    fn has_remaining_mut(&self) -> bool {
+        match self {
+            Self::Left(l) => l.has_remaining_mut(),
+            Self::Right(r) => r.has_remaining_mut(),
+        }
+    }
+
+    #[inline]
+    fn put<T: Buf>(&mut self, src: T) {
+        match self {
+            Self::Left(l) => l.put(src),
+            Self::Right(r) => r.put(src),
+        }
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        match self {
+            Self::Left(l) => l.put_slice(src),
+            Self::Right(r) => r.put_slice(src),
+        }
+    }
+}
+
+// ===== io traits =====
+
+impl<L: AsyncBufRead, R: AsyncBufRead> AsyncBufRead for Either<L, R> {
+    #[inline]
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<usize>> {
+        match self.project() {
+            Either::Left(l) => l.poll_fill_buf(cx),
+            Either::Right(r) => r.poll_fill_buf(cx),
+        }
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        match self {
+            Self::Left(l) => l.chunk(),
+            Self::Right(r) => r.chunk(),
+        }
+    }
+
+    #[inline]
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        match self.project() {
+            Either::Left(l) => l.consume(amt),
+            Either::Right(r) => r.consume(amt),
+        }
+    }
+
+    #[inline]
+    fn split_to(self: Pin<&mut Self>, at: usize) -> Bytes {
+        match self.project() {
+            Either::Left(l) => l.split_to(at),
+            Either::Right(r) => r.split_to(at),
+        }
+    }
+
+    #[inline]
+    fn poll_read_until(
+        self: Pin<&mut Self>,
+        delim: u8,
+        cx: &mut Context,
+    ) -> Poll<io::Result<Bytes>> {
+        match self.project() {
+            Either::Left(l) => l.poll_read_until(delim, cx),
+            Either::Right(r) => r.poll_read_until(delim, cx),
+        }
+    }
+
+    #[inline]
+    fn poll_read_line(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<Bytes>> {
+        match self.project() {
+            Either::Left(l) => l.poll_read_line(cx),
+            Either::Right(r) => r.poll_read_line(cx),
+        }
+    }
+}
+
+impl<L: AsyncRead, R: AsyncRead> AsyncRead for Either<L, R> {
+    #[inline]
+    fn poll_read(
+        self: Pin<&mut Self>,
+        buf: impl BufMut,
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        match self.project() {
+            Either::Left(l) => l.poll_read(buf, cx),
+            Either::Right(r) => r.poll_read(buf, cx),
+        }
+    }
+
+    #[inline]
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        buf: impl BufMut,
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        match self.project() {
+            Either::Left(l) => l.poll_read_vectored(buf, cx),
+            Either::Right(r) => r.poll_read_vectored(buf, cx),
+        }
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        match self {
+            Self::Left(l) => l.is_read_vectored(),
+            Self::Right(r) => r.is_read_vectored(),
+        }
+    }
+}
+
+impl<L: AsyncWrite, R: AsyncWrite> AsyncWrite for Either<L, R> {
+    #[inline]
+    fn poll_write(self: Pin<&mut Self>, buf: &[u8], cx: &mut Context) -> Poll<io::Result<usize>> {
+        match self.project() {
+            Either::Left(l) => l.poll_write(buf, cx),
+            Either::Right(r) => r.poll_write(buf, cx),
+        }
+    }
+
+    #[inline]
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        match self.project() {
+            Either::Left(l) => l.poll_flush(cx),
+            Either::Right(r) => r.poll_flush(cx),
+        }
+    }
+
+    #[inline]
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        match self.project() {
+            Either::Left(l) => l.poll_shutdown(cx),
+            Either::Right(r) => r.poll_shutdown(cx),
+        }
+    }
+
+    #[inline]
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        bufs: &[io::IoSlice],
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        match self.project() {
+            Either::Left(l) => l.poll_write_vectored(bufs, cx),
+            Either::Right(r) => r.poll_write_vectored(bufs, cx),
+        }
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            Self::Left(l) => l.is_write_vectored(),
+            Self::Right(r) => r.is_write_vectored(),
+        }
+    }
+
+    #[inline]
+    fn poll_write_buf(
+        self: Pin<&mut Self>,
+        buf: impl Buf,
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        match self.project() {
+            Either::Left(l) => l.poll_write_buf(buf, cx),
+            Either::Right(r) => r.poll_write_buf(buf, cx),
+        }
+    }
+
+    #[inline]
+    fn poll_write_all_buf(
+        self: Pin<&mut Self>,
+        buf: impl Buf,
+        cx: &mut Context,
+    ) -> Poll<io::Result<()>> {
+        match self.project() {
+            Either::Left(l) => l.poll_write_all_buf(buf, cx),
+            Either::Right(r) => r.poll_write_all_buf(buf, cx),
+        }
+    }
+
+    #[inline]
+    fn poll_write_buf_vectored(
+        self: Pin<&mut Self>,
+        buf: impl Buf,
+        cx: &mut Context,
+    ) -> Poll<io::Result<()>> {
+        match self.project() {
+            Either::Left(l) => l.poll_write_buf_vectored(buf, cx),
+            Either::Right(r) => r.poll_write_buf_vectored(buf, cx),
+        }
+    }
+}
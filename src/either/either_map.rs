@@ -1,8 +1,11 @@
 use std::fmt;
 use std::io;
 use std::pin::Pin;
+use std::task::{Context, Poll};
 
+use crate::bytes::{Buf, BufMut};
 use crate::either::Either;
+use crate::io::{AsyncRead, AsyncWrite};
 
 /// Represent either type that implement the same trait.
 ///
@@ -16,6 +19,20 @@ pub enum EitherMap<L, R> {
     Right(R),
 }
 
+impl<L, R> EitherMap<L, R> {
+    /// Project pinned `EitherMap` into `Either` of pinned variant.
+    pub(crate) fn project(self: Pin<&mut Self>) -> Either<Pin<&mut L>, Pin<&mut R>> {
+        // SAFETY: self is pinned
+        // no `Drop`, nor manual `Unpin` implementation.
+        unsafe {
+            match self.get_unchecked_mut() {
+                Self::Left(l) => Either::Left(Pin::new_unchecked(l)),
+                Self::Right(r) => Either::Right(Pin::new_unchecked(r)),
+            }
+        }
+    }
+}
+
 impl<L, R> From<Either<L, R>> for EitherMap<L, R> {
     #[inline]
     fn from(value: Either<L, R>) -> Self {
@@ -152,3 +169,96 @@ impl<L: AsRef<str>, R: AsRef<str>> AsRef<str> for EitherMap<L, R> {
         }
     }
 }
+
+// ===== io traits =====
+
+// io traits have no output type, so it is the same as `Either`
+
+impl<L: AsyncRead, R: AsyncRead> AsyncRead for EitherMap<L, R> {
+    #[inline]
+    fn poll_read(
+        self: Pin<&mut Self>,
+        buf: impl BufMut,
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.project()).poll_read(buf, cx)
+    }
+
+    #[inline]
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        buf: impl BufMut,
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.project()).poll_read_vectored(buf, cx)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        match self {
+            Self::Left(l) => l.is_read_vectored(),
+            Self::Right(r) => r.is_read_vectored(),
+        }
+    }
+}
+
+impl<L: AsyncWrite, R: AsyncWrite> AsyncWrite for EitherMap<L, R> {
+    #[inline]
+    fn poll_write(self: Pin<&mut Self>, buf: &[u8], cx: &mut Context) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.project()).poll_write(buf, cx)
+    }
+
+    #[inline]
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.project()).poll_flush(cx)
+    }
+
+    #[inline]
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.project()).poll_shutdown(cx)
+    }
+
+    #[inline]
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        bufs: &[io::IoSlice],
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.project()).poll_write_vectored(bufs, cx)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            Self::Left(l) => l.is_write_vectored(),
+            Self::Right(r) => r.is_write_vectored(),
+        }
+    }
+
+    #[inline]
+    fn poll_write_buf(
+        self: Pin<&mut Self>,
+        buf: impl Buf,
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.project()).poll_write_buf(buf, cx)
+    }
+
+    #[inline]
+    fn poll_write_all_buf(
+        self: Pin<&mut Self>,
+        buf: impl Buf,
+        cx: &mut Context,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.project()).poll_write_all_buf(buf, cx)
+    }
+
+    #[inline]
+    fn poll_write_buf_vectored(
+        self: Pin<&mut Self>,
+        buf: impl Buf,
+        cx: &mut Context,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.project()).poll_write_buf_vectored(buf, cx)
+    }
+}
@@ -0,0 +1,187 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+
+use crate::bytes::{Bytes, BytesMut};
+
+/// Read bytes asynchronously with an internal buffer.
+///
+/// This trait is analogous to the `std::io::BufRead` trait, but integrates with the asynchronous
+/// task system. In contrast with `BufRead`, buffered bytes can be split off as [`Bytes`], which
+/// for [`BytesMut`] backed implementation does not require copying.
+///
+/// [`BytesMut`]: crate::bytes::BytesMut
+pub trait AsyncBufRead {
+    /// Attempt to read more bytes into the internal buffer.
+    ///
+    /// Bytes is appended to the internal buffer regardless of the current buffer length.
+    ///
+    /// On success, returns `Poll::Ready(Ok(num_bytes_read))`. If `num_bytes_read` is `0`, it
+    /// implies that EOF has been reached.
+    ///
+    /// If no data is available for reading, the method returns `Poll::Pending` and arranges for
+    /// the current task (via `cx.waker()`) to receive a notification when the object becomes
+    /// readable or is closed.
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<usize>>;
+
+    /// Returns the buffered bytes.
+    ///
+    /// This function does not read from the underlying io.
+    fn chunk(&self) -> &[u8];
+
+    /// Consumes `amt` bytes from the internal buffer.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if `amt > self.chunk().len()`.
+    fn consume(self: Pin<&mut Self>, amt: usize);
+
+    /// Splits the buffered bytes at the given index, returning the leading bytes.
+    ///
+    /// The default implementation copies the bytes, implementor backed by [`BytesMut`] should
+    /// override it to avoid copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.chunk().len()`.
+    fn split_to(mut self: Pin<&mut Self>, at: usize) -> Bytes {
+        let bytes = Bytes::copy_from_slice(&self.chunk()[..at]);
+        self.as_mut().consume(at);
+        bytes
+    }
+
+    /// Attempt to read bytes until the `delim` byte or EOF is reached.
+    ///
+    /// On success, returns the bytes up to and including the delimiter. If EOF is reached before
+    /// the delimiter is found, returns all the remaining bytes, which may be empty.
+    ///
+    /// This function is stateless, on `Poll::Pending` the buffered bytes is not consumed.
+    fn poll_read_until(
+        mut self: Pin<&mut Self>,
+        delim: u8,
+        cx: &mut Context,
+    ) -> Poll<io::Result<Bytes>> {
+        let mut searched = 0;
+        loop {
+            let chunk = self.chunk();
+            if let Some(n) = chunk[searched..].iter().position(|&b| b == delim) {
+                return Poll::Ready(Ok(self.as_mut().split_to(searched + n + 1)));
+            }
+            searched = chunk.len();
+
+            if ready!(self.as_mut().poll_fill_buf(cx))? == 0 {
+                return Poll::Ready(Ok(self.as_mut().split_to(searched)));
+            }
+        }
+    }
+
+    /// Attempt to read bytes until a newline (the `0xA` byte) or EOF is reached.
+    ///
+    /// On success, returns the bytes up to and including the newline. If EOF is reached before
+    /// the newline is found, returns all the remaining bytes, which may be empty.
+    ///
+    /// This function is stateless, on `Poll::Pending` the buffered bytes is not consumed.
+    #[inline]
+    fn poll_read_line(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<Bytes>> {
+        self.poll_read_until(b'\n', cx)
+    }
+}
+
+impl AsyncBufRead for &[u8] {
+    #[inline]
+    fn poll_fill_buf(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<usize>> {
+        // all bytes is already buffered
+        Poll::Ready(Ok(0))
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        self
+    }
+
+    #[inline]
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        let me = self.get_mut();
+        *me = &me[amt..];
+    }
+}
+
+impl AsyncBufRead for BytesMut {
+    #[inline]
+    fn poll_fill_buf(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<usize>> {
+        // all bytes is already buffered
+        Poll::Ready(Ok(0))
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        self
+    }
+
+    #[inline]
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        crate::bytes::Buf::advance(self.get_mut(), amt);
+    }
+
+    #[inline]
+    fn split_to(self: Pin<&mut Self>, at: usize) -> Bytes {
+        BytesMut::split_to(self.get_mut(), at).freeze()
+    }
+}
+
+/// This macro make sure to forward ALL methods which may be overriden by the implementor.
+///
+/// Otherwise, it will use default implementation.
+macro_rules! delegate {
+    (<$($T:tt),*>, |$s:ident|$map:expr) => {
+        #[inline]
+        fn poll_fill_buf($s: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<usize>> {
+            $($T)*::poll_fill_buf($map, cx)
+        }
+
+        #[inline]
+        fn chunk(&self) -> &[u8] {
+            $($T)*::chunk(self)
+        }
+
+        #[inline]
+        fn consume($s: Pin<&mut Self>, amt: usize) {
+            $($T)*::consume($map, amt)
+        }
+
+        #[inline]
+        fn split_to($s: Pin<&mut Self>, at: usize) -> Bytes {
+            $($T)*::split_to($map, at)
+        }
+
+        #[inline]
+        fn poll_read_until(
+            $s: Pin<&mut Self>,
+            delim: u8,
+            cx: &mut Context,
+        ) -> Poll<io::Result<Bytes>> {
+            $($T)*::poll_read_until($map, delim, cx)
+        }
+
+        #[inline]
+        fn poll_read_line($s: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<Bytes>> {
+            $($T)*::poll_read_line($map, cx)
+        }
+    }
+}
+
+impl<T: AsyncBufRead + Unpin + ?Sized> AsyncBufRead for &mut T {
+    delegate!(<T>, |self|Pin::new(self.get_mut()));
+}
+
+impl<T: AsyncBufRead + Unpin + ?Sized> AsyncBufRead for Box<T> {
+    delegate!(<T>, |self|Pin::new(self.get_mut()));
+}
+
+impl<T> AsyncBufRead for Pin<T>
+where
+    T: std::ops::DerefMut,
+    T::Target: AsyncBufRead,
+{
+    delegate!(<T,::,Target>, |self|Pin::as_deref_mut(self));
+}
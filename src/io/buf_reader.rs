@@ -0,0 +1,284 @@
+use std::io;
+use std::mem;
+use std::pin::Pin;
+use std::{cmp, slice};
+use std::task::{Context, Poll, ready};
+
+use crate::bytes::{Buf, BufMut, Bytes, BytesMut};
+use crate::io::{AsyncBufRead, AsyncRead};
+
+const DEFAULT_READ_SIZE: usize = 8 * 1024;
+
+/// Adds buffering to any [`AsyncRead`].
+///
+/// `BufReader` owns a [`BytesMut`] read buffer. Data read from the underlying io is appended to
+/// the buffer, and can be handed out as [`Bytes`] without copying via [`split_to`].
+///
+/// # Read size
+///
+/// Before each read to the underlying io, `BufReader` ensure that the buffer have at least
+/// [`read_size`] bytes of spare capacity. It will try to reclaim leftover capacity of the buffer
+/// before allocating.
+///
+/// # Examples
+///
+/// ```
+/// # use std::task::{Context, Poll, Waker};
+/// use tcio::io::BufReader;
+///
+/// let mut cx = Context::from_waker(Waker::noop());
+/// let mut reader = BufReader::new(&b"Hello World!"[..]);
+///
+/// assert!(matches!(reader.poll_fill_buf(&mut cx), Poll::Ready(Ok(12))));
+///
+/// let hello = reader.split_to(5);
+/// assert_eq!(&hello, &b"Hello"[..]);
+/// assert_eq!(reader.buffer(), &b" World!"[..]);
+/// ```
+///
+/// [`split_to`]: BufReader::split_to
+/// [`read_size`]: BufReader::read_size
+#[derive(Debug)]
+pub struct BufReader<IO> {
+    io: IO,
+    /// Leading buffered bytes which is frozen by [`Cursor`][crate::io::Cursor].
+    ///
+    /// INVARIANT: if not empty, `frozen` is immediately followed by `buf` in the same allocation
+    frozen: Bytes,
+    buf: BytesMut,
+    read_size: usize,
+}
+
+impl<IO> BufReader<IO> {
+    /// Creates new [`BufReader`] with default read size.
+    #[inline]
+    pub fn new(io: IO) -> Self {
+        Self::with_capacity(DEFAULT_READ_SIZE, io)
+    }
+
+    /// Creates new [`BufReader`] with at least specified capacity.
+    ///
+    /// The given `capacity` is also used as the [`read_size`][BufReader::read_size].
+    #[inline]
+    pub fn with_capacity(capacity: usize, io: IO) -> Self {
+        Self {
+            io,
+            frozen: Bytes::new(),
+            buf: BytesMut::with_capacity(capacity),
+            read_size: capacity,
+        }
+    }
+
+    /// Creates new [`BufReader`] from existing buffer.
+    ///
+    /// Bytes already contained in `buf` will be read before reading from the `io`.
+    #[inline]
+    pub fn from_parts(io: IO, buf: BytesMut) -> Self {
+        Self {
+            io,
+            frozen: Bytes::new(),
+            buf,
+            read_size: DEFAULT_READ_SIZE,
+        }
+    }
+
+    /// Returns a shared reference to the underlying io.
+    #[inline]
+    pub fn inner(&self) -> &IO {
+        &self.io
+    }
+
+    /// Returns a mutable reference to the underlying io.
+    ///
+    /// Note that reading directly from the underlying io may corrupt the stream.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut IO {
+        &mut self.io
+    }
+
+    /// Returns the buffered bytes.
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        if self.frozen.is_empty() {
+            return &self.buf;
+        }
+        // SAFETY: invariant `frozen` is immediately followed by `buf` in the same allocation, both
+        // are initialized and immutable while `self` is borrowed
+        unsafe { slice::from_raw_parts(self.frozen.as_ptr(), self.frozen.len() + self.buf.len()) }
+    }
+
+    /// Returns a mutable reference to the read buffer.
+    ///
+    /// If bytes handed out by a [`Cursor`][crate::io::Cursor] that was not committed is still
+    /// alive, the buffered bytes is copied into a new allocation.
+    #[inline]
+    pub fn buffer_mut(&mut self) -> &mut BytesMut {
+        self.thaw(0);
+        &mut self.buf
+    }
+
+    /// Returns the minimum spare capacity ensured before reading from the underlying io.
+    #[inline]
+    pub fn read_size(&self) -> usize {
+        self.read_size
+    }
+
+    /// Sets the minimum spare capacity ensured before reading from the underlying io.
+    ///
+    /// Note that it does not guarantee that a single read will fill the entire spare capacity.
+    #[inline]
+    pub fn set_read_size(&mut self, read_size: usize) {
+        self.read_size = read_size;
+    }
+
+    /// Splits the buffered bytes at the given index, returning the leading bytes.
+    ///
+    /// Afterwards the buffer contains bytes `[at, len)`, and the returned [`Bytes`] contains bytes
+    /// `[0, at)`.
+    ///
+    /// This is an `O(1)` operation, the returned `Bytes` shares the read buffer allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.buffer().len()`.
+    #[inline]
+    pub fn split_to(&mut self, at: usize) -> Bytes {
+        if self.frozen.is_empty() {
+            return self.buf.split_to(at).freeze();
+        }
+        self.freeze_to(at);
+        self.frozen.split_to(at)
+    }
+
+    /// Removes all the buffered bytes, returning them as [`Bytes`].
+    ///
+    /// This is identical to `self.split_to(self.buffer().len())`.
+    #[inline]
+    pub fn split(&mut self) -> Bytes {
+        self.split_to(self.buffer().len())
+    }
+
+    /// Consumes this `BufReader`, returns the underlying io.
+    ///
+    /// Note that any buffered bytes are lost.
+    #[inline]
+    pub fn into_inner(self) -> IO {
+        self.io
+    }
+
+    /// Consumes this `BufReader`, returns the underlying io and the read buffer.
+    #[inline]
+    pub fn into_parts(mut self) -> (IO, BytesMut) {
+        self.thaw(0);
+        (self.io, self.buf)
+    }
+
+    /// Advance the buffered bytes by `cnt`.
+    pub(crate) fn consume(&mut self, cnt: usize) {
+        match cnt.checked_sub(self.frozen.len()) {
+            Some(cnt) => {
+                self.frozen = Bytes::new();
+                self.buf.advance(cnt);
+            }
+            None => self.frozen.advance(cnt),
+        }
+    }
+
+    /// Split the leading buffered bytes into frozen bytes, such that it contains at least `len`
+    /// bytes, and returns it.
+    pub(crate) fn freeze_to(&mut self, len: usize) -> &Bytes {
+        if let Some(cnt @ 1..) = len.checked_sub(self.frozen.len()) {
+            let more = self.buf.split_to(cnt).freeze();
+            if self.frozen.is_empty() {
+                self.frozen = more;
+            } else if let Err(more) = self.frozen.try_unsplit(more) {
+                unreachable!("frozen bytes is not followed by the buffer: {more:?}");
+            }
+        }
+        &self.frozen
+    }
+
+    /// Restore the frozen bytes back into the read buffer, with at least `additional` spare
+    /// capacity if it is copied.
+    ///
+    /// Frozen bytes can only be mutable again if all the bytes handed out is dropped, otherwise
+    /// it is copied.
+    fn thaw(&mut self, additional: usize) {
+        let frozen = mem::take(&mut self.frozen);
+        if let Err(frozen) = self.buf.try_unsplit_frozen(frozen) {
+            let len = frozen.len() + self.buf.len();
+            let mut capacity = frozen.len() + self.buf.capacity();
+            if capacity - len < additional {
+                // follow `BytesMut::reserve` logic, `max(exponential, additional)`
+                capacity = cmp::max(capacity * 2, len + additional);
+            }
+
+            let mut restored = BytesMut::with_capacity(capacity);
+            restored.extend_from_slice(&frozen);
+            restored.extend_from_slice(&self.buf);
+            self.buf = restored;
+        }
+    }
+}
+
+impl<IO: AsyncRead + Unpin> BufReader<IO> {
+    /// Attempt to read more bytes from the underlying io into the buffer.
+    ///
+    /// Bytes is appended to the buffer regardless of the current buffer length.
+    ///
+    /// On success, returns `Poll::Ready(Ok(num_bytes_read))`. If `num_bytes_read` is `0`, it
+    /// implies that EOF has been reached.
+    pub fn poll_fill_buf(&mut self, cx: &mut Context) -> Poll<io::Result<usize>> {
+        if !self.frozen.is_empty() && self.buf.capacity() - self.buf.len() < self.read_size {
+            // reallocating the buffer would break frozen bytes contiguity
+            self.thaw(self.read_size);
+        }
+        self.buf.reserve(self.read_size);
+        Pin::new(&mut self.io).poll_read(&mut self.buf, cx)
+    }
+}
+
+impl<IO: AsyncRead + Unpin> AsyncRead for BufReader<IO> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        mut buf: impl BufMut,
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        let me = self.get_mut();
+
+        if me.buffer().is_empty() {
+            // bypass the internal buffer if the read would have been copied entirely anyway
+            if buf.remaining_mut() >= me.read_size {
+                return Pin::new(&mut me.io).poll_read(buf, cx);
+            }
+            ready!(me.poll_fill_buf(cx))?;
+        }
+
+        let cnt = me.buffer().len().min(buf.remaining_mut());
+        buf.put_slice(&me.buffer()[..cnt]);
+        me.consume(cnt);
+        Poll::Ready(Ok(cnt))
+    }
+}
+
+impl<IO: AsyncRead + Unpin> AsyncBufRead for BufReader<IO> {
+    #[inline]
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<usize>> {
+        BufReader::poll_fill_buf(self.get_mut(), cx)
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        self.buffer()
+    }
+
+    #[inline]
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        BufReader::consume(self.get_mut(), amt);
+    }
+
+    #[inline]
+    fn split_to(self: Pin<&mut Self>, at: usize) -> Bytes {
+        BufReader::split_to(self.get_mut(), at)
+    }
+}
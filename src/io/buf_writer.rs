@@ -0,0 +1,278 @@
+use std::collections::VecDeque;
+use std::io::{self, IoSlice};
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+
+use crate::bytes::{Buf, BufMut, Bytes, BytesMut, UninitSlice};
+use crate::io::AsyncWrite;
+
+const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+/// [`Bytes`] shorter than this is copied instead of queued.
+const COPY_THRESHOLD: usize = 256;
+
+const MAX_VECTORED: usize = 64;
+
+/// Adds buffering to any [`AsyncWrite`].
+///
+/// `BufWriter` owns a [`BytesMut`] write buffer and implements [`BufMut`], so encoders can write
+/// directly into it. Large [`Bytes`] can be queued via [`push_bytes`] without copying.
+///
+/// When flushing, the write buffer and the queued `Bytes` is written together via
+/// [`poll_write_vectored`] if the underlying io [`is_write_vectored`].
+///
+/// Note that writing via [`BufMut`] never flush the buffer, it is the caller responsibility to
+/// call [`poll_flush_buf`] when [`is_full`] returns `true`.
+///
+/// # Examples
+///
+/// ```
+/// # use std::task::{Context, Poll, Waker};
+/// use tcio::bytes::{BufMut, Bytes};
+/// use tcio::io::BufWriter;
+///
+/// let mut cx = Context::from_waker(Waker::noop());
+/// let mut writer = BufWriter::new(Vec::new());
+///
+/// writer.put_u16(5);
+/// writer.push_bytes(Bytes::from_static(b"Hello"));
+///
+/// assert!(writer.poll_flush_buf(&mut cx).is_ready());
+/// assert_eq!(writer.inner(), b"\x00\x05Hello");
+/// ```
+///
+/// [`push_bytes`]: BufWriter::push_bytes
+/// [`poll_flush_buf`]: BufWriter::poll_flush_buf
+/// [`is_full`]: BufWriter::is_full
+/// [`poll_write_vectored`]: AsyncWrite::poll_write_vectored
+/// [`is_write_vectored`]: AsyncWrite::is_write_vectored
+#[derive(Debug)]
+pub struct BufWriter<IO> {
+    io: IO,
+    /// Segments that is written before `buf`.
+    queue: VecDeque<Bytes>,
+    /// Total length of `queue`.
+    queue_len: usize,
+    buf: BytesMut,
+    capacity: usize,
+}
+
+impl<IO> BufWriter<IO> {
+    /// Creates new [`BufWriter`] with default capacity.
+    #[inline]
+    pub fn new(io: IO) -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY, io)
+    }
+
+    /// Creates new [`BufWriter`] with specified capacity.
+    ///
+    /// The given `capacity` is the amount of bytes accumulated before [`is_full`] returns `true`.
+    ///
+    /// [`is_full`]: BufWriter::is_full
+    #[inline]
+    pub fn with_capacity(capacity: usize, io: IO) -> Self {
+        Self {
+            io,
+            queue: VecDeque::new(),
+            queue_len: 0,
+            buf: BytesMut::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Returns a shared reference to the underlying io.
+    #[inline]
+    pub fn inner(&self) -> &IO {
+        &self.io
+    }
+
+    /// Returns a mutable reference to the underlying io.
+    ///
+    /// Note that writing directly to the underlying io may corrupt the stream.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut IO {
+        &mut self.io
+    }
+
+    /// Returns the amount of bytes accumulated before [`is_full`] returns `true`.
+    ///
+    /// [`is_full`]: BufWriter::is_full
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of buffered bytes, including the queued [`Bytes`].
+    #[inline]
+    pub fn buffered_len(&self) -> usize {
+        self.queue_len + self.buf.len()
+    }
+
+    /// Returns `true` if the buffered bytes reach the configured capacity.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.buffered_len() >= self.capacity
+    }
+
+    /// Queue `bytes` to be written after the currently buffered bytes.
+    ///
+    /// Large `bytes` is not copied, it will be written directly from its allocation.
+    pub fn push_bytes(&mut self, bytes: Bytes) {
+        if bytes.len() < COPY_THRESHOLD {
+            self.buf.extend_from_slice(&bytes);
+            return;
+        }
+        if !self.buf.is_empty() {
+            let head = self.buf.split().freeze();
+            self.queue_len += head.len();
+            self.queue.push_back(head);
+        }
+        self.queue_len += bytes.len();
+        self.queue.push_back(bytes);
+    }
+
+    /// Consumes this `BufWriter`, returns the underlying io.
+    ///
+    /// Note that any buffered bytes are lost.
+    #[inline]
+    pub fn into_inner(self) -> IO {
+        self.io
+    }
+
+    fn consume(&mut self, mut cnt: usize) {
+        while let Some(front) = self.queue.front_mut() {
+            if cnt < front.len() {
+                front.advance(cnt);
+                self.queue_len -= cnt;
+                return;
+            }
+            cnt -= front.len();
+            self.queue_len -= front.len();
+            self.queue.pop_front();
+        }
+        self.buf.advance(cnt);
+    }
+}
+
+impl<IO: AsyncWrite + Unpin> BufWriter<IO> {
+    /// Attempt to write all the buffered bytes into the underlying io.
+    ///
+    /// Note that this does not flush the underlying io.
+    pub fn poll_flush_buf(&mut self, cx: &mut Context) -> Poll<io::Result<()>> {
+        while self.buffered_len() != 0 {
+            let write = if self.io.is_write_vectored() {
+                let mut slices = [IoSlice::new(&[]); MAX_VECTORED];
+                let cnt = chunks_vectored(&self.queue, &self.buf, &mut slices);
+                ready!(Pin::new(&mut self.io).poll_write_vectored(&slices[..cnt], cx))?
+            } else {
+                let chunk = match self.queue.front() {
+                    Some(front) => front.as_slice(),
+                    None => self.buf.as_slice(),
+                };
+                ready!(Pin::new(&mut self.io).poll_write(chunk, cx))?
+            };
+
+            if write == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            self.consume(write);
+        }
+
+        // reuse the write buffer allocation
+        self.buf.try_reclaim_full();
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+fn chunks_vectored<'a>(
+    queue: &'a VecDeque<Bytes>,
+    buf: &'a BytesMut,
+    dst: &mut [IoSlice<'a>],
+) -> usize {
+    let mut cnt = 0;
+    let bufs = queue.iter().map(Bytes::as_slice).chain(Some(buf.as_slice()));
+    for (dst, buf) in dst.iter_mut().zip(bufs.filter(|b| !b.is_empty())) {
+        *dst = IoSlice::new(buf);
+        cnt += 1;
+    }
+    cnt
+}
+
+impl<IO> BufMut for BufWriter<IO> {
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        self.buf.remaining_mut()
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        self.buf.chunk_mut()
+    }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        unsafe { self.buf.advance_mut(cnt) }
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        self.buf.extend_from_slice(src);
+    }
+}
+
+impl<IO: AsyncWrite + Unpin> AsyncWrite for BufWriter<IO> {
+    fn poll_write(self: Pin<&mut Self>, buf: &[u8], cx: &mut Context) -> Poll<io::Result<usize>> {
+        let me = self.get_mut();
+
+        if me.buffered_len() + buf.len() > me.capacity {
+            ready!(me.poll_flush_buf(cx))?;
+        }
+
+        if buf.len() >= me.capacity {
+            Pin::new(&mut me.io).poll_write(buf, cx)
+        } else {
+            me.buf.extend_from_slice(buf);
+            Poll::Ready(Ok(buf.len()))
+        }
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        bufs: &[IoSlice],
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        let me = self.get_mut();
+        let len = bufs.iter().map(|b| b.len()).sum::<usize>();
+
+        if me.buffered_len() + len > me.capacity {
+            ready!(me.poll_flush_buf(cx))?;
+        }
+
+        if len >= me.capacity {
+            Pin::new(&mut me.io).poll_write_vectored(bufs, cx)
+        } else {
+            for buf in bufs {
+                me.buf.extend_from_slice(buf);
+            }
+            Poll::Ready(Ok(len))
+        }
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        true
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        let me = self.get_mut();
+        ready!(me.poll_flush_buf(cx))?;
+        Pin::new(&mut me.io).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        let me = self.get_mut();
+        ready!(me.poll_flush_buf(cx))?;
+        Pin::new(&mut me.io).poll_shutdown(cx)
+    }
+}
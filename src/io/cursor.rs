@@ -0,0 +1,161 @@
+use std::io;
+use std::task::{Context, Poll, ready};
+
+use crate::bytes::Bytes;
+use crate::io::{AsyncRead, BufReader};
+
+// Cursor split the leading bytes of the read buffer into frozen bytes of the reader, so that
+// handed out chunks can share the read buffer allocation.
+//
+// (heap)   : [--------------------------]
+// frozen   : [--------]
+// buf      :           [-------_________] (buffer and spare capacity)
+// read     : [-----]
+//
+// on commit, `read` bytes of the reader is consumed, on drop, the frozen bytes is kept in the
+// reader as is, so rolling back never copy, and the next cursor continue from the same frozen
+// bytes
+//
+// the frozen bytes is only restored back into the mutable buffer when it is required, see
+// `BufReader::thaw`
+
+/// A transactional reader over [`BufReader`].
+///
+/// `Cursor` track the read bytes internally, without advancing the underlying [`BufReader`].
+/// When the parsing is complete, calling [`commit`] will actually advance the `BufReader` by the
+/// amount of read bytes.
+///
+/// If `Cursor` is dropped without calling [`commit`], for example because of an error or
+/// `Poll::Pending`, the `BufReader` is left as if nothing was read, so parsing can be retried
+/// from the beginning.
+///
+/// This struct is created by [`BufReader::cursor`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::task::{Context, Poll, Waker, ready};
+/// use tcio::bytes::Bytes;
+/// use tcio::io::{AsyncRead, BufReader};
+///
+/// fn poll_parse<IO>(io: &mut BufReader<IO>, cx: &mut Context) -> Poll<std::io::Result<Bytes>>
+/// where
+///     IO: AsyncRead + Unpin,
+/// {
+///     let mut cursor = io.cursor();
+///
+///     let len = ready!(cursor.poll_read(1, cx)?)[0];
+///     let payload = ready!(cursor.poll_read(len as usize, cx)?);
+///
+///     cursor.commit();
+///     Poll::Ready(Ok(payload))
+/// }
+///
+/// let mut cx = Context::from_waker(Waker::noop());
+/// let mut io = BufReader::new(&b"\x05Hello\x06World!"[..]);
+///
+/// let Poll::Ready(Ok(hello)) = poll_parse(&mut io, &mut cx) else { unreachable!() };
+/// assert_eq!(&hello, &b"Hello"[..]);
+///
+/// let Poll::Ready(Ok(world)) = poll_parse(&mut io, &mut cx) else { unreachable!() };
+/// assert_eq!(&world, &b"World!"[..]);
+/// ```
+///
+/// [`commit`]: Cursor::commit
+#[derive(Debug)]
+pub struct Cursor<'a, IO> {
+    reader: &'a mut BufReader<IO>,
+    /// Read position relative to the start of the reader buffer.
+    read: usize,
+}
+
+impl<IO> BufReader<IO> {
+    /// Creates a [`Cursor`] for transactional reading.
+    #[inline]
+    pub fn cursor(&mut self) -> Cursor<'_, IO> {
+        Cursor {
+            reader: self,
+            read: 0,
+        }
+    }
+}
+
+impl<IO> Cursor<'_, IO> {
+    /// Returns the number of bytes read by the cursor.
+    #[inline]
+    pub fn read_len(&self) -> usize {
+        self.read
+    }
+
+    /// Returns the number of bytes that can be read without reading from the underlying io.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.reader.buffer().len() - self.read
+    }
+
+    /// Moves the cursor back by `cnt` bytes.
+    ///
+    /// Subsequent read will returns the same bytes that have been read before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cnt > self.read_len()`.
+    #[inline]
+    pub fn step_back(&mut self, cnt: usize) {
+        self.read = self
+            .read
+            .checked_sub(cnt)
+            .expect("cannot step back pass the cursor start");
+    }
+
+    /// Read a single already buffered byte.
+    ///
+    /// This function does not read from the underlying io, returns `None` if there is no more
+    /// buffered bytes.
+    pub fn pop_front(&mut self) -> Option<u8> {
+        let byte = *self.reader.buffer().get(self.read)?;
+        self.read += 1;
+        Some(byte)
+    }
+
+    /// Returns all the bytes read by the cursor.
+    ///
+    /// The returned [`Bytes`] shares the read buffer allocation.
+    pub fn original(&mut self) -> Bytes {
+        self.reader.freeze_to(self.read).slice(..self.read)
+    }
+
+    /// Consumes the cursor, advancing the underlying [`BufReader`] by the number of bytes read.
+    pub fn commit(self) {
+        self.reader.consume(self.read);
+    }
+}
+
+impl<IO: AsyncRead + Unpin> Cursor<'_, IO> {
+    /// Attempt to read exactly `len` bytes.
+    ///
+    /// If the buffered bytes is insufficient, more bytes is read from the underlying io.
+    ///
+    /// The returned [`Bytes`] shares the read buffer allocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::ErrorKind::UnexpectedEof`] if the underlying io reached EOF before `len`
+    /// bytes can be read, or [`io::ErrorKind::InvalidInput`] if the end of the read overflows
+    /// `usize`.
+    pub fn poll_read(&mut self, len: usize, cx: &mut Context) -> Poll<io::Result<Bytes>> {
+        let Some(end) = self.read.checked_add(len) else {
+            return Poll::Ready(Err(io::ErrorKind::InvalidInput.into()));
+        };
+
+        while self.reader.buffer().len() < end {
+            if ready!(self.reader.poll_fill_buf(cx))? == 0 {
+                return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+            }
+        }
+
+        let chunk = self.reader.freeze_to(end).slice(self.read..end);
+        self.read = end;
+        Poll::Ready(Ok(chunk))
+    }
+}
@@ -1,6 +1,23 @@
 //! Provide utilities for working with asynchronous IO.
 mod read;
 mod write;
+mod buf_read;
+mod read_ext;
+mod write_ext;
+
+mod buf_reader;
+mod buf_writer;
+mod cursor;
 
 pub use read::AsyncRead;
 pub use write::AsyncWrite;
+pub use buf_read::AsyncBufRead;
+pub use read_ext::{AsyncReadExt, Read, ReadBuf, ReadExact, ReadToEnd};
+pub use write_ext::{AsyncWriteExt, Flush, Shutdown, Write, WriteAll, WriteAllBuf, WriteBuf};
+
+pub use buf_reader::BufReader;
+pub use buf_writer::BufWriter;
+pub use cursor::Cursor;
+
+#[cfg(test)]
+pub(crate) mod test;
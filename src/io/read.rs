@@ -2,7 +2,7 @@ use std::io;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
-use crate::bytes::BufMut;
+use crate::bytes::{BufMut, UninitSlice};
 
 /// Read bytes asynchronously.
 ///
@@ -24,6 +24,55 @@ pub trait AsyncRead {
     /// the current task (via `cx.waker()`) to receive a notification when the object becomes
     /// readable or is closed.
     fn poll_read(self: Pin<&mut Self>, buf: impl BufMut, cx: &mut Context) -> Poll<io::Result<usize>>;
+
+    /// Like [`poll_read`], except that it reads into multiple chunks of `buf` given by
+    /// [`BufMut::chunks_vectored_mut`].
+    ///
+    /// The default implementation calls [`poll_read`] for each chunk in order, until a chunk is
+    /// not filled entirely. If a following call returns an error or `Poll::Pending`, the bytes
+    /// already read is returned instead. Implementor with a vectored read primitive should
+    /// override this method and [`is_read_vectored`].
+    ///
+    /// [`poll_read`]: AsyncRead::poll_read
+    /// [`is_read_vectored`]: AsyncRead::is_read_vectored
+    fn poll_read_vectored(
+        mut self: Pin<&mut Self>,
+        mut buf: impl BufMut,
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        const LEN: usize = 64;
+        let mut chunks = [(); LEN].map(|_| UninitSlice::new(&mut []));
+        let cnt = buf.chunks_vectored_mut(&mut chunks);
+
+        let mut read = 0;
+        for chunk in &mut chunks[..cnt] {
+            let len = chunk.len();
+            let n = match self.as_mut().poll_read(&mut **chunk, cx) {
+                Poll::Ready(Ok(n)) => n,
+                Poll::Ready(Err(err)) if read == 0 => return Poll::Ready(Err(err)),
+                Poll::Pending if read == 0 => return Poll::Pending,
+                // the error or readiness is observed again in the next call
+                _ => break,
+            };
+            read += n;
+            if n < len {
+                break;
+            }
+        }
+
+        // SAFETY: `read` bytes of the chunks is initialized by `poll_read` in order
+        unsafe { buf.advance_mut(read) };
+        Poll::Ready(Ok(read))
+    }
+
+    /// Determines if this reader has an efficient [`poll_read_vectored`] implementation.
+    ///
+    /// The default implementation returns `false`.
+    ///
+    /// [`poll_read_vectored`]: AsyncRead::poll_read_vectored
+    fn is_read_vectored(&self) -> bool {
+        false
+    }
 }
 
 impl AsyncRead for &[u8] {
@@ -50,6 +99,20 @@ impl<T: AsyncRead + Unpin + ?Sized> AsyncRead for &mut T {
     ) -> Poll<io::Result<usize>> {
         T::poll_read(Pin::new(self.get_mut()), buf, cx)
     }
+
+    #[inline]
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        buf: impl BufMut,
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        T::poll_read_vectored(Pin::new(self.get_mut()), buf, cx)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        T::is_read_vectored(self)
+    }
 }
 
 impl<T: AsyncRead + Unpin + ?Sized> AsyncRead for Box<T> {
@@ -61,6 +124,20 @@ impl<T: AsyncRead + Unpin + ?Sized> AsyncRead for Box<T> {
     ) -> Poll<io::Result<usize>> {
         T::poll_read(Pin::new(self.get_mut()), buf, cx)
     }
+
+    #[inline]
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        buf: impl BufMut,
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        T::poll_read_vectored(Pin::new(self.get_mut()), buf, cx)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        T::is_read_vectored(self)
+    }
 }
 
 impl<T> AsyncRead for Pin<T>
@@ -76,4 +153,18 @@ where
     ) -> Poll<io::Result<usize>> {
         T::Target::poll_read(Pin::as_deref_mut(self), buf, cx)
     }
+
+    #[inline]
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        buf: impl BufMut,
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        T::Target::poll_read_vectored(Pin::as_deref_mut(self), buf, cx)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        T::Target::is_read_vectored(self)
+    }
 }
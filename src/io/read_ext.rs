@@ -0,0 +1,192 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+
+use crate::bytes::{BufMut, BytesMut};
+use crate::io::AsyncRead;
+
+/// Minimum spare capacity reserved before each read in [`ReadToEnd`].
+const READ_TO_END_RESERVE: usize = 4 * 1024;
+
+/// An extension trait which adds utility methods to [`AsyncRead`] types.
+///
+/// # Examples
+///
+/// ```
+/// # async fn app() -> std::io::Result<()> {
+/// use tcio::io::AsyncReadExt;
+///
+/// let mut io = &b"Hello World!"[..];
+/// let mut buf = [0u8; 5];
+///
+/// io.read_exact(&mut buf).await?;
+/// assert_eq!(&buf, b"Hello");
+/// # Ok(())
+/// # }
+/// # assert!(matches!(
+/// #     std::pin::pin!(app())
+/// #         .poll(&mut std::task::Context::from_waker(std::task::Waker::noop())),
+/// #     std::task::Poll::Ready(Ok(()))
+/// # ));
+/// ```
+pub trait AsyncReadExt: AsyncRead {
+    /// Read bytes into `buf`.
+    ///
+    /// Resolves to the number of bytes read. `0` implies that EOF has been reached, or `buf` is
+    /// empty.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If the future is dropped before completion, no bytes were read.
+    #[inline]
+    fn read<'a>(&'a mut self, buf: &'a mut [u8]) -> Read<'a, Self>
+    where
+        Self: Unpin,
+    {
+        Read { reader: self, buf }
+    }
+
+    /// Read bytes into an instance of [`BufMut`].
+    ///
+    /// Resolves to the number of bytes read, `buf` is advanced by the same amount. `0` implies that
+    /// EOF has been reached, or `buf` have no remaining capacity.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If the future is dropped before completion, no bytes were read.
+    #[inline]
+    fn read_buf<'a, B: BufMut>(&'a mut self, buf: &'a mut B) -> ReadBuf<'a, Self, B>
+    where
+        Self: Unpin,
+    {
+        ReadBuf { reader: self, buf }
+    }
+
+    /// Read the exact number of bytes required to fill `buf`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::ErrorKind::UnexpectedEof`] if EOF is reached before `buf` is filled.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is **not** cancel safe. If the future is dropped before completion, some bytes
+    /// may have been read into `buf`, and there is no way to tell how many.
+    #[inline]
+    fn read_exact<'a>(&'a mut self, buf: &'a mut [u8]) -> ReadExact<'a, Self>
+    where
+        Self: Unpin,
+    {
+        ReadExact {
+            reader: self,
+            buf,
+            filled: 0,
+        }
+    }
+
+    /// Read all bytes until EOF, appending them to `buf`.
+    ///
+    /// Resolves to the total number of bytes read.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe in the sense that no bytes is lost. If the future is dropped
+    /// before completion, all bytes read so far is already appended to `buf`.
+    #[inline]
+    fn read_to_end<'a>(&'a mut self, buf: &'a mut BytesMut) -> ReadToEnd<'a, Self>
+    where
+        Self: Unpin,
+    {
+        ReadToEnd {
+            reader: self,
+            buf,
+            read: 0,
+        }
+    }
+}
+
+impl<T: AsyncRead + ?Sized> AsyncReadExt for T {}
+
+/// Future returned by [`AsyncReadExt::read`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Read<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut [u8],
+}
+
+impl<R: AsyncRead + Unpin + ?Sized> Future for Read<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let me = self.get_mut();
+        Pin::new(&mut *me.reader).poll_read(&mut *me.buf, cx)
+    }
+}
+
+/// Future returned by [`AsyncReadExt::read_buf`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadBuf<'a, R: ?Sized, B> {
+    reader: &'a mut R,
+    buf: &'a mut B,
+}
+
+impl<R: AsyncRead + Unpin + ?Sized, B: BufMut> Future for ReadBuf<'_, R, B> {
+    type Output = io::Result<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let me = self.get_mut();
+        Pin::new(&mut *me.reader).poll_read(&mut *me.buf, cx)
+    }
+}
+
+/// Future returned by [`AsyncReadExt::read_exact`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadExact<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut [u8],
+    filled: usize,
+}
+
+impl<R: AsyncRead + Unpin + ?Sized> Future for ReadExact<'_, R> {
+    type Output = io::Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let me = self.get_mut();
+        while me.filled < me.buf.len() {
+            let read = ready!(Pin::new(&mut *me.reader).poll_read(&mut me.buf[me.filled..], cx))?;
+            if read == 0 {
+                return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+            }
+            me.filled += read;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Future returned by [`AsyncReadExt::read_to_end`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadToEnd<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut BytesMut,
+    read: usize,
+}
+
+impl<R: AsyncRead + Unpin + ?Sized> Future for ReadToEnd<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let me = self.get_mut();
+        loop {
+            me.buf.reserve(READ_TO_END_RESERVE);
+            let read = ready!(Pin::new(&mut *me.reader).poll_read(&mut *me.buf, cx))?;
+            if read == 0 {
+                return Poll::Ready(Ok(me.read));
+            }
+            me.read += read;
+        }
+    }
+}
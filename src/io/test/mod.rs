@@ -0,0 +1,131 @@
+use std::collections::VecDeque;
+use std::io::{self, IoSlice};
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+use crate::bytes::BufMut;
+use crate::io::{AsyncRead, AsyncWrite};
+
+mod test_buf_read;
+mod test_buf_reader;
+mod test_buf_writer;
+mod test_cursor;
+mod test_ext;
+
+fn cx() -> Context<'static> {
+    Context::from_waker(Waker::noop())
+}
+
+/// Busy poll the future, mock io in this module never register the waker.
+fn block_on<F: Future>(f: F) -> F::Output {
+    let mut f = std::pin::pin!(f);
+    loop {
+        if let Poll::Ready(ok) = f.as_mut().poll(&mut cx()) {
+            return ok;
+        }
+    }
+}
+
+/// Mock io that returns `Poll::Pending` before yielding each chunk.
+struct Partial {
+    chunks: VecDeque<Vec<u8>>,
+    pending: bool,
+}
+
+impl Partial {
+    fn new<const N: usize>(chunks: [&[u8]; N]) -> Self {
+        Self {
+            chunks: chunks.into_iter().map(<[u8]>::to_vec).collect(),
+            pending: true,
+        }
+    }
+
+    fn from_chunks<'a>(chunks: impl IntoIterator<Item = &'a [u8]>) -> Self {
+        Self {
+            chunks: chunks.into_iter().map(<[u8]>::to_vec).collect(),
+            pending: true,
+        }
+    }
+}
+
+impl AsyncRead for Partial {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        mut buf: impl BufMut,
+        _: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        let me = self.get_mut();
+        me.pending = !me.pending;
+        if !me.pending {
+            return Poll::Pending;
+        }
+        let Some(mut chunk) = me.chunks.pop_front() else {
+            return Poll::Ready(Ok(0));
+        };
+        let cnt = chunk.len().min(buf.remaining_mut());
+        buf.put_slice(&chunk[..cnt]);
+        if cnt != chunk.len() {
+            me.chunks.push_front(chunk.split_off(cnt));
+            me.pending = false;
+        }
+        Poll::Ready(Ok(cnt))
+    }
+}
+
+/// Mock io that records the written bytes and the number of write calls.
+pub(crate) struct Recorder {
+    pub(crate) written: Vec<u8>,
+    pub(crate) writes: usize,
+    vectored: bool,
+    /// Maximum bytes accepted in a single write.
+    limit: usize,
+}
+
+impl Recorder {
+    pub(crate) fn new(vectored: bool, limit: usize) -> Self {
+        Self {
+            written: Vec::new(),
+            writes: 0,
+            vectored,
+            limit,
+        }
+    }
+}
+
+impl AsyncWrite for Recorder {
+    fn poll_write(self: Pin<&mut Self>, buf: &[u8], _: &mut Context) -> Poll<io::Result<usize>> {
+        let me = self.get_mut();
+        let cnt = buf.len().min(me.limit);
+        me.written.extend_from_slice(&buf[..cnt]);
+        me.writes += 1;
+        Poll::Ready(Ok(cnt))
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        bufs: &[IoSlice],
+        _: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        let me = self.get_mut();
+        let mut cnt = 0;
+        for buf in bufs {
+            let n = buf.len().min(me.limit - cnt);
+            me.written.extend_from_slice(&buf[..n]);
+            cnt += n;
+        }
+        me.writes += 1;
+        Poll::Ready(Ok(cnt))
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.vectored
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
@@ -0,0 +1,136 @@
+use std::pin::Pin;
+use std::task::Poll;
+
+use super::{Partial, cx};
+use crate::either::Either;
+use crate::io::{AsyncBufRead, BufReader};
+
+#[test]
+fn test_buf_read_until() {
+    let mut cx = cx();
+    let mut reader = BufReader::new(Partial::new([b"Content-Ty", b"pe: text/html\r\nHost", b": "]));
+    let mut reader = Pin::new(&mut reader);
+
+    assert!(reader.as_mut().poll_read_line(&mut cx).is_pending());
+    assert!(reader.as_mut().poll_read_line(&mut cx).is_pending());
+    assert_eq!(reader.chunk(), b"Content-Ty");
+
+    let Poll::Ready(Ok(line)) = reader.as_mut().poll_read_line(&mut cx) else {
+        unreachable!()
+    };
+    assert_eq!(&line, &b"Content-Type: text/html\r\n"[..]);
+    assert_eq!(reader.chunk(), b"Host");
+
+    assert!(reader.as_mut().poll_read_until(b':', &mut cx).is_pending());
+    let Poll::Ready(Ok(name)) = reader.as_mut().poll_read_until(b':', &mut cx) else {
+        unreachable!()
+    };
+    assert_eq!(&name, &b"Host:"[..]);
+
+    // EOF
+    assert!(reader.as_mut().poll_read_line(&mut cx).is_pending());
+    let Poll::Ready(Ok(rest)) = reader.as_mut().poll_read_line(&mut cx) else {
+        unreachable!()
+    };
+    assert_eq!(&rest, &b" "[..]);
+
+    assert!(reader.as_mut().poll_read_line(&mut cx).is_pending());
+    let Poll::Ready(Ok(rest)) = reader.as_mut().poll_read_line(&mut cx) else {
+        unreachable!()
+    };
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn test_buf_read_zero_copy() {
+    let mut cx = cx();
+    let mut reader = BufReader::new(&b"foo\nbar\n"[..]);
+    let mut reader = Pin::new(&mut reader);
+
+    let Poll::Ready(Ok(foo)) = reader.as_mut().poll_read_line(&mut cx) else { unreachable!() };
+    let ptr = reader.chunk().as_ptr();
+    let Poll::Ready(Ok(bar)) = reader.as_mut().poll_read_line(&mut cx) else { unreachable!() };
+
+    assert_eq!(&foo, &b"foo\n"[..]);
+    assert_eq!(&bar, &b"bar\n"[..]);
+    assert_eq!(bar.as_ptr(), ptr);
+}
+
+#[test]
+fn test_buf_read_slice() {
+    let mut cx = cx();
+    let mut reader = &b"foo\nbar"[..];
+    let mut reader = Pin::new(&mut reader);
+
+    let Poll::Ready(Ok(foo)) = reader.as_mut().poll_read_line(&mut cx) else { unreachable!() };
+    assert_eq!(&foo, &b"foo\n"[..]);
+
+    let Poll::Ready(Ok(bar)) = reader.as_mut().poll_read_line(&mut cx) else { unreachable!() };
+    assert_eq!(&bar, &b"bar"[..]);
+    assert!(reader.chunk().is_empty());
+}
+
+#[test]
+fn test_buf_read_either() {
+    let mut cx = cx();
+    let readers: [Either<&[u8], BufReader<&[u8]>>; 2] = [
+        Either::Left(&b"foo\nbar"[..]),
+        Either::Right(BufReader::new(&b"foo\nbar"[..])),
+    ];
+
+    for mut reader in readers {
+        let mut reader = Pin::new(&mut reader);
+        let Poll::Ready(Ok(foo)) = reader.as_mut().poll_read_line(&mut cx) else {
+            unreachable!()
+        };
+        assert_eq!(&foo, &b"foo\n"[..]);
+
+        reader.as_mut().consume(1);
+        assert_eq!(reader.chunk(), b"ar");
+    }
+}
+
+#[test]
+fn test_buf_read_either_forward() {
+    use std::io;
+    use std::task::Context;
+
+    use crate::bytes::Bytes;
+
+    /// Mock io with specialized `poll_read_until`.
+    struct Specialized;
+
+    impl AsyncBufRead for Specialized {
+        fn poll_fill_buf(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<usize>> {
+            unreachable!("should use specialized read until")
+        }
+
+        fn chunk(&self) -> &[u8] {
+            &[]
+        }
+
+        fn consume(self: Pin<&mut Self>, _: usize) {}
+
+        fn poll_read_until(
+            self: Pin<&mut Self>,
+            delim: u8,
+            _: &mut Context,
+        ) -> Poll<io::Result<Bytes>> {
+            Poll::Ready(Ok(Bytes::copy_from_slice(&[delim])))
+        }
+    }
+
+    let mut cx = cx();
+    let mut reader = Either::<&[u8], Specialized>::Right(Specialized);
+    let mut reader = Pin::new(&mut reader);
+
+    let Poll::Ready(Ok(line)) = reader.as_mut().poll_read_line(&mut cx) else {
+        unreachable!()
+    };
+    assert_eq!(&line, &b"\n"[..]);
+
+    let Poll::Ready(Ok(until)) = reader.as_mut().poll_read_until(b':', &mut cx) else {
+        unreachable!()
+    };
+    assert_eq!(&until, &b":"[..]);
+}
@@ -0,0 +1,73 @@
+use std::pin::Pin;
+use std::task::Poll;
+
+use super::{Partial, cx};
+use crate::bytes::BytesMut;
+use crate::io::{AsyncRead, BufReader};
+
+#[test]
+fn test_buf_reader_fill_buf() {
+    let mut cx = cx();
+    let mut reader = BufReader::new(Partial::new([b"Hello ", b"World!"]));
+
+    assert!(reader.poll_fill_buf(&mut cx).is_pending());
+    assert!(matches!(reader.poll_fill_buf(&mut cx), Poll::Ready(Ok(6))));
+    assert_eq!(reader.buffer(), &b"Hello "[..]);
+
+    assert!(reader.poll_fill_buf(&mut cx).is_pending());
+    assert!(matches!(reader.poll_fill_buf(&mut cx), Poll::Ready(Ok(6))));
+    assert_eq!(reader.buffer(), &b"Hello World!"[..]);
+
+    assert!(reader.poll_fill_buf(&mut cx).is_pending());
+    assert!(matches!(reader.poll_fill_buf(&mut cx), Poll::Ready(Ok(0))));
+}
+
+#[test]
+fn test_buf_reader_split_zero_copy() {
+    let mut cx = cx();
+    let mut reader = BufReader::new(&b"Hello World!"[..]);
+
+    assert!(matches!(reader.poll_fill_buf(&mut cx), Poll::Ready(Ok(12))));
+    let ptr = reader.buffer().as_ptr();
+
+    let hello = reader.split_to(6);
+    assert_eq!(hello.as_ptr(), ptr);
+    assert_eq!(&hello, &b"Hello "[..]);
+
+    let world = reader.split();
+    assert_eq!(world.as_ptr(), ptr.wrapping_add(6));
+    assert_eq!(&world, &b"World!"[..]);
+    assert!(reader.buffer().is_empty());
+}
+
+#[test]
+fn test_buf_reader_read_size() {
+    let mut cx = cx();
+    let mut reader = BufReader::with_capacity(4, Partial::new([b"Hello World!"]));
+    assert_eq!(reader.read_size(), 4);
+
+    reader.set_read_size(32);
+    let _ = reader.poll_fill_buf(&mut cx);
+    assert!(reader.buffer_mut().capacity() >= 32);
+}
+
+#[test]
+fn test_buf_reader_async_read() {
+    let mut cx = cx();
+    let mut reader = BufReader::with_capacity(64, Partial::new([b"Hello ", b"World!"]));
+
+    let mut out = [0u8; 4];
+    let mut dst = &mut out[..];
+    assert!(Pin::new(&mut reader).poll_read(&mut dst, &mut cx).is_pending());
+    assert!(matches!(Pin::new(&mut reader).poll_read(&mut dst, &mut cx), Poll::Ready(Ok(4))));
+    assert_eq!(&out, b"Hell");
+    assert_eq!(reader.buffer(), &b"o "[..]);
+
+    // large destination bypass the internal buffer
+    let mut out = BytesMut::with_capacity(128);
+    assert!(matches!(Pin::new(&mut reader).poll_read(&mut out, &mut cx), Poll::Ready(Ok(2))));
+    assert!(Pin::new(&mut reader).poll_read(&mut out, &mut cx).is_pending());
+    assert!(matches!(Pin::new(&mut reader).poll_read(&mut out, &mut cx), Poll::Ready(Ok(6))));
+    assert_eq!(&out, &b"o World!"[..]);
+    assert!(reader.buffer().is_empty());
+}
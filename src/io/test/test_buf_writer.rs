@@ -0,0 +1,86 @@
+use std::pin::Pin;
+use std::task::Poll;
+
+use super::{Recorder, cx};
+use crate::bytes::{BufMut, Bytes};
+use crate::io::{AsyncWrite, BufWriter};
+
+const PAYLOAD: &[u8] = &[0xAB; 1024];
+
+#[test]
+fn test_buf_writer_vectored() {
+    let mut cx = cx();
+    let mut writer = BufWriter::new(Recorder::new(true, usize::MAX));
+
+    writer.put_u16(1024);
+    writer.push_bytes(Bytes::from_static(PAYLOAD));
+    writer.put_slice(b"end");
+    assert_eq!(writer.buffered_len(), 2 + 1024 + 3);
+    assert_eq!(writer.inner().writes, 0);
+
+    assert!(writer.poll_flush_buf(&mut cx).is_ready());
+    assert_eq!(writer.buffered_len(), 0);
+
+    let io = writer.inner();
+    assert_eq!(io.writes, 1);
+    assert_eq!(&io.written[..2], b"\x04\x00");
+    assert_eq!(&io.written[2..1026], PAYLOAD);
+    assert_eq!(&io.written[1026..], b"end");
+}
+
+#[test]
+fn test_buf_writer_partial_write() {
+    let mut cx = cx();
+
+    for vectored in [true, false] {
+        let mut writer = BufWriter::new(Recorder::new(vectored, 7));
+
+        writer.put_slice(b"Header");
+        writer.push_bytes(Bytes::from_static(PAYLOAD));
+        writer.put_slice(b"Trailer");
+        writer.push_bytes(Bytes::from_static(PAYLOAD));
+
+        assert!(writer.poll_flush_buf(&mut cx).is_ready());
+        assert_eq!(writer.buffered_len(), 0);
+
+        let mut expected = b"Header".to_vec();
+        expected.extend_from_slice(PAYLOAD);
+        expected.extend_from_slice(b"Trailer");
+        expected.extend_from_slice(PAYLOAD);
+        assert_eq!(writer.inner().written, expected);
+    }
+}
+
+#[test]
+fn test_buf_writer_small_bytes_copied() {
+    let mut writer = BufWriter::new(Recorder::new(true, usize::MAX));
+    let small = Bytes::copy_from_slice(b"Hello");
+
+    writer.push_bytes(small.clone());
+    assert_eq!(writer.buffered_len(), 5);
+    assert!(small.is_unique());
+}
+
+#[test]
+fn test_buf_writer_async_write() {
+    let mut cx = cx();
+    let mut writer = BufWriter::with_capacity(16, Recorder::new(false, usize::MAX));
+
+    let n = Pin::new(&mut writer).poll_write(b"Hello", &mut cx);
+    assert!(matches!(n, Poll::Ready(Ok(5))));
+    assert_eq!(writer.inner().writes, 0);
+
+    // exceeding capacity flush the buffer first
+    let n = Pin::new(&mut writer).poll_write(b" World, Hello!", &mut cx);
+    assert!(matches!(n, Poll::Ready(Ok(14))));
+    assert_eq!(writer.inner().written, b"Hello");
+    assert!(!writer.is_full());
+
+    // large write bypass the buffer
+    let n = Pin::new(&mut writer).poll_write(PAYLOAD, &mut cx);
+    assert!(matches!(n, Poll::Ready(Ok(1024))));
+
+    assert!(Pin::new(&mut writer).poll_flush(&mut cx).is_ready());
+    assert_eq!(writer.inner().written.len(), 5 + 14 + 1024);
+    assert_eq!(writer.inner().writes, 3);
+}
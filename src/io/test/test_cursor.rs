@@ -0,0 +1,235 @@
+use std::io;
+use std::task::{Context, Poll, ready};
+
+use super::{Partial, cx};
+use crate::bytes::Bytes;
+use crate::io::{AsyncRead, BufReader};
+
+/// `[len: u8][payload: len][tag: 2]`
+const MESSAGE: &[u8] = b"\x0cHello World!\xAB\xCD";
+
+fn poll_parse<IO>(io: &mut BufReader<IO>, cx: &mut Context) -> Poll<io::Result<(Bytes, Bytes)>>
+where
+    IO: AsyncRead + Unpin,
+{
+    let mut cursor = io.cursor();
+
+    let len = ready!(cursor.poll_read(1, cx)?)[0];
+    let payload = ready!(cursor.poll_read(len as usize, cx)?);
+    let tag = ready!(cursor.poll_read(2, cx)?);
+
+    cursor.commit();
+    Poll::Ready(Ok((payload, tag)))
+}
+
+#[test]
+fn test_cursor_partial_read() {
+    let mut cx = cx();
+
+    for at in 1..MESSAGE.len() {
+        let (a, b) = MESSAGE.split_at(at);
+        let mut io = BufReader::new(Partial::new([a, b]));
+
+        // pending before the first chunk
+        assert!(poll_parse(&mut io, &mut cx).is_pending());
+        assert!(io.buffer().is_empty());
+
+        // pending before the second chunk, buffer is not advanced
+        assert!(poll_parse(&mut io, &mut cx).is_pending());
+        assert_eq!(io.buffer(), a);
+
+        let Poll::Ready(Ok((payload, tag))) = poll_parse(&mut io, &mut cx) else {
+            panic!("failed to parse at {at}");
+        };
+        assert_eq!(&payload, &b"Hello World!"[..]);
+        assert_eq!(&tag, &b"\xAB\xCD"[..]);
+        assert!(io.buffer().is_empty());
+    }
+}
+
+#[test]
+fn test_cursor_byte_by_byte() {
+    let mut cx = cx();
+    let chunks: [&[u8]; MESSAGE.len()] = std::array::from_fn(|i| &MESSAGE[i..i + 1]);
+    let mut io = BufReader::new(Partial::new(chunks));
+
+    for i in 0..MESSAGE.len() {
+        assert!(poll_parse(&mut io, &mut cx).is_pending());
+        assert_eq!(io.buffer(), &MESSAGE[..i]);
+    }
+
+    let Poll::Ready(Ok((payload, tag))) = poll_parse(&mut io, &mut cx) else {
+        unreachable!()
+    };
+    assert_eq!(&payload, &b"Hello World!"[..]);
+    assert_eq!(&tag, &b"\xAB\xCD"[..]);
+}
+
+#[test]
+fn test_cursor_zero_copy() {
+    let mut cx = cx();
+    let mut io = BufReader::new(MESSAGE);
+
+    // rollback after all chunks dropped
+    let ptr = {
+        let mut cursor = io.cursor();
+        let Poll::Ready(Ok(chunk)) = cursor.poll_read(4, &mut cx) else { unreachable!() };
+        chunk.as_ptr()
+    };
+    assert_eq!(io.buffer().as_ptr(), ptr);
+    assert_eq!(io.buffer(), MESSAGE);
+
+    let Poll::Ready(Ok((payload, _))) = poll_parse(&mut io, &mut cx) else { unreachable!() };
+    assert_eq!(payload.as_ptr(), ptr.wrapping_add(1));
+}
+
+#[test]
+fn test_cursor_rollback_alive_chunk() {
+    let mut cx = cx();
+    let mut io = BufReader::new(MESSAGE);
+
+    let kept = {
+        let mut cursor = io.cursor();
+        let Poll::Ready(Ok(chunk)) = cursor.poll_read(6, &mut cx) else { unreachable!() };
+        chunk
+    };
+    assert_eq!(&kept, &MESSAGE[..6]);
+    assert_eq!(io.buffer(), MESSAGE);
+
+    let Poll::Ready(Ok((payload, tag))) = poll_parse(&mut io, &mut cx) else { unreachable!() };
+    assert_eq!(&payload, &b"Hello World!"[..]);
+    assert_eq!(&tag, &b"\xAB\xCD"[..]);
+    assert_eq!(&kept, &MESSAGE[..6]);
+}
+
+#[test]
+fn test_cursor_rollback_buffer_mut() {
+    let mut cx = cx();
+    let mut io = BufReader::new(MESSAGE);
+
+    let kept = {
+        let mut cursor = io.cursor();
+        let Poll::Ready(Ok(chunk)) = cursor.poll_read(6, &mut cx) else { unreachable!() };
+        chunk
+    };
+
+    // the frozen bytes is copied, because it is still observed by `kept`
+    io.buffer_mut()[1] = b'h';
+    assert_eq!(&kept, &MESSAGE[..6]);
+    assert_eq!(&io.buffer()[1..6], b"hello");
+    drop(kept);
+
+    // the frozen bytes is restored without copying
+    let ptr = io.buffer().as_ptr();
+    let chunk = match io.cursor().poll_read(3, &mut cx) {
+        Poll::Ready(Ok(chunk)) => chunk,
+        _ => unreachable!(),
+    };
+    drop(chunk);
+    io.buffer_mut()[0] = b'\x00';
+    assert_eq!(io.buffer().as_ptr(), ptr);
+}
+
+#[test]
+fn test_cursor_rollback_held_frame() {
+    /// `[len: u16][payload: len]`
+    fn poll_frame<IO>(io: &mut BufReader<IO>, cx: &mut Context) -> Poll<io::Result<Bytes>>
+    where
+        IO: AsyncRead + Unpin,
+    {
+        let mut cursor = io.cursor();
+        let len = ready!(cursor.poll_read(2, cx)?);
+        let len = u16::from_be_bytes([len[0], len[1]]);
+        let payload = ready!(cursor.poll_read(len.into(), cx)?);
+        cursor.commit();
+        Poll::Ready(Ok(payload))
+    }
+
+    let mut cx = cx();
+    let mut input = b"\x00\x05Hello\x27\x10".to_vec();
+    input.extend((0..10_000).map(|i| i as u8));
+    let mut chunks = vec![&input[..9]];
+    chunks.extend(input[9..].chunks(100));
+
+    let mut io = BufReader::new(Partial::from_chunks(chunks));
+    let hello = loop {
+        if let Poll::Ready(frame) = poll_frame(&mut io, &mut cx) {
+            break frame.unwrap();
+        }
+    };
+    assert_eq!(&hello, &b"Hello"[..]);
+
+    // the earlier frame is held while the large frame is pending
+    let mut pending = 0;
+    let mut ptrs = vec![];
+    let large = loop {
+        match poll_frame(&mut io, &mut cx) {
+            Poll::Ready(frame) => break frame.unwrap(),
+            Poll::Pending => pending += 1,
+        }
+        if ptrs.last() != Some(&io.buffer().as_ptr()) {
+            ptrs.push(io.buffer().as_ptr());
+        }
+    };
+    assert_eq!(&hello, &b"Hello"[..]);
+    assert_eq!(&large, &input[9..]);
+    assert!(pending >= 100);
+    // the buffer grows exponentially, instead of reallocated on every rollback
+    assert!(ptrs.len() <= 3, "reallocated {} times", ptrs.len());
+}
+
+#[test]
+fn test_cursor_step_back() {
+    let mut cx = cx();
+    let mut io = BufReader::new(MESSAGE);
+    let mut cursor = io.cursor();
+
+    let Poll::Ready(Ok(a)) = cursor.poll_read(6, &mut cx) else { unreachable!() };
+    cursor.step_back(5);
+    assert_eq!(cursor.read_len(), 1);
+
+    let Poll::Ready(Ok(b)) = cursor.poll_read(5, &mut cx) else { unreachable!() };
+    assert_eq!(&b, &a[1..]);
+    assert_eq!(b.as_ptr(), a.as_ptr().wrapping_add(1));
+
+    cursor.step_back(2);
+    cursor.commit();
+    assert_eq!(io.buffer(), &MESSAGE[4..]);
+}
+
+#[test]
+fn test_cursor_pop_front() {
+    let mut cx = cx();
+    let mut io = BufReader::new(MESSAGE);
+    let mut cursor = io.cursor();
+
+    // nothing is buffered
+    assert!(cursor.pop_front().is_none());
+
+    let Poll::Ready(Ok(_)) = cursor.poll_read(1, &mut cx) else { unreachable!() };
+    assert_eq!(cursor.pop_front(), Some(b'H'));
+    assert_eq!(cursor.pop_front(), Some(b'e'));
+    assert_eq!(cursor.remaining(), MESSAGE.len() - 3);
+
+    cursor.step_back(1);
+    assert_eq!(cursor.pop_front(), Some(b'e'));
+    assert_eq!(&cursor.original(), &MESSAGE[..3]);
+
+    cursor.commit();
+    assert_eq!(io.buffer(), &MESSAGE[3..]);
+}
+
+#[test]
+fn test_cursor_eof() {
+    let mut cx = cx();
+    let mut io = BufReader::new(&MESSAGE[..4]);
+
+    let Poll::Ready(Err(err)) = poll_parse(&mut io, &mut cx) else { unreachable!() };
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    assert_eq!(io.buffer(), &MESSAGE[..4]);
+
+    let mut cursor = io.cursor();
+    assert!(cursor.pop_front().is_some());
+    let Poll::Ready(Err(err)) = cursor.poll_read(usize::MAX, &mut cx) else { unreachable!() };
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+}
@@ -0,0 +1,177 @@
+use std::io;
+
+use super::{Partial, Recorder, block_on};
+use crate::bytes::{Buf, BytesMut};
+use crate::io::{AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+#[test]
+fn test_read_exact() {
+    let mut io = Partial::new([b"Hel", b"lo", b" World!"]);
+    let mut buf = [0u8; 8];
+
+    block_on(io.read_exact(&mut buf)).unwrap();
+    assert_eq!(&buf, b"Hello Wo");
+
+    let mut buf = [0u8; 8];
+    let err = block_on(io.read_exact(&mut buf)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn test_read_to_end() {
+    let mut io = Partial::new([b"Hel", b"lo", b" World!"]);
+    let mut buf = BytesMut::from(&b">"[..]);
+
+    assert_eq!(block_on(io.read_to_end(&mut buf)).unwrap(), 12);
+    assert_eq!(&buf, &b">Hello World!"[..]);
+}
+
+#[test]
+fn test_read_buf() {
+    let mut io = &b"Hello World!"[..];
+    let mut buf = Vec::new();
+
+    assert_eq!(block_on(io.read_buf(&mut buf)).unwrap(), 12);
+    assert_eq!(buf, b"Hello World!");
+
+    let mut buf = [0u8; 4];
+    assert_eq!(block_on(io.read(&mut buf)).unwrap(), 0);
+}
+
+#[test]
+fn test_write_all() {
+    let mut io = Recorder::new(false, 3);
+
+    assert_eq!(block_on(io.write(b"Hello")).unwrap(), 3);
+    block_on(io.write_all(b"lo World!")).unwrap();
+    assert_eq!(io.written, b"Hello World!");
+    assert_eq!(io.writes, 4);
+
+    let mut buf = &b"Hello"[..];
+    assert_eq!(block_on(io.write_buf(&mut buf)).unwrap(), 3);
+    assert_eq!(buf, b"lo");
+
+    block_on(io.write_all_buf(&mut buf)).unwrap();
+    assert!(!buf.has_remaining());
+
+    block_on(io.flush()).unwrap();
+    block_on(io.shutdown()).unwrap();
+}
+
+#[test]
+fn test_either_io() {
+    use crate::either::{Either, EitherMap};
+
+    let mut io = Either::<&[u8], Partial>::Right(Partial::new([b"Hello", b" World!"]));
+    let mut buf = [0u8; 12];
+    block_on(io.read_exact(&mut buf)).unwrap();
+    assert_eq!(&buf, b"Hello World!");
+
+    let mut io = EitherMap::<Partial, &[u8]>::Right(&b"Hello"[..]);
+    let mut buf = BytesMut::new();
+    assert_eq!(block_on(io.read_to_end(&mut buf)).unwrap(), 5);
+
+    let mut io = Either::<Vec<u8>, Recorder>::Right(Recorder::new(true, usize::MAX));
+    assert!(io.is_write_vectored());
+    let mut buf = crate::bytes::Bytes::from_static(b"Hello").chain(&b" World!"[..]);
+    block_on(io.write_all_buf(&mut buf)).unwrap();
+    let Either::Right(recorder) = &io else { unreachable!() };
+    assert_eq!(recorder.written, b"Hello World!");
+
+    let io = EitherMap::<Vec<u8>, Recorder>::Left(Vec::new());
+    assert!(!io.is_write_vectored());
+}
+
+#[test]
+fn test_either_read_vectored() {
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use crate::bytes::{BufMut, UninitSlice};
+    use crate::either::{Either, EitherMap};
+    use crate::io::AsyncRead;
+
+    /// Mock io that fills every vectored chunk with `b'v'`.
+    struct Vectored;
+
+    impl AsyncRead for Vectored {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            _: impl BufMut,
+            _: &mut Context,
+        ) -> Poll<io::Result<usize>> {
+            unreachable!("should use vectored read")
+        }
+
+        fn poll_read_vectored(
+            self: Pin<&mut Self>,
+            mut buf: impl BufMut,
+            _: &mut Context,
+        ) -> Poll<io::Result<usize>> {
+            let mut chunks = [UninitSlice::new(&mut []), UninitSlice::new(&mut [])];
+            let cnt = buf.chunks_vectored_mut(&mut chunks);
+            let mut read = 0;
+            for chunk in &mut chunks[..cnt] {
+                let len = chunk.len();
+                chunk.copy_from_slice(&vec![b'v'; len]);
+                read += len;
+            }
+            // SAFETY: `read` bytes of the chunks is initialized
+            unsafe { buf.advance_mut(read) };
+            Poll::Ready(Ok(read))
+        }
+
+        fn is_read_vectored(&self) -> bool {
+            true
+        }
+    }
+
+    let mut a = [0u8; 2];
+    let mut b = [0u8; 3];
+
+    let mut io = Either::<&[u8], Vectored>::Right(Vectored);
+    assert!(io.is_read_vectored());
+    let buf = (&mut a[..]).chain_mut(&mut b[..]);
+    let poll = Pin::new(&mut io).poll_read_vectored(buf, &mut super::cx());
+    assert!(matches!(poll, Poll::Ready(Ok(5))));
+    assert_eq!((&a, &b), (b"vv", b"vvv"));
+
+    let mut io = EitherMap::<Vectored, &[u8]>::Left(Vectored);
+    assert!(io.is_read_vectored());
+    let poll = Pin::new(&mut io).poll_read_vectored(&mut a[..], &mut super::cx());
+    assert!(matches!(poll, Poll::Ready(Ok(2))));
+
+    let io = Either::<&[u8], Vectored>::Left(&b""[..]);
+    assert!(!io.is_read_vectored());
+}
+
+#[test]
+fn test_read_vectored_default() {
+    use std::pin::Pin;
+    use std::task::Poll;
+
+    use crate::bytes::BufMut;
+    use crate::io::AsyncRead;
+
+    let mut a = [0u8; 2];
+    let mut b = [0u8; 3];
+
+    // every chunk is filled
+    let mut io = &b"hello world"[..];
+    let buf = (&mut a[..]).chain_mut(&mut b[..]);
+    let poll = Pin::new(&mut io).poll_read_vectored(buf, &mut super::cx());
+    assert!(matches!(poll, Poll::Ready(Ok(5))));
+    assert_eq!((&a, &b), (b"he", b"llo"));
+    assert_eq!(io, b" world");
+
+    // the bytes read before `Poll::Pending` is returned
+    let mut io = Partial::new([&b"ab"[..], b"cde"]);
+    let mut buf = (&mut a[..]).chain_mut(&mut b[..]);
+    let poll = Pin::new(&mut io).poll_read_vectored(&mut buf, &mut super::cx());
+    assert!(poll.is_pending());
+    let poll = Pin::new(&mut io).poll_read_vectored(&mut buf, &mut super::cx());
+    assert!(matches!(poll, Poll::Ready(Ok(2))));
+    let poll = Pin::new(&mut io).poll_read_vectored(&mut buf, &mut super::cx());
+    assert!(matches!(poll, Poll::Ready(Ok(3))));
+    assert_eq!((&a, &b), (b"ab", b"cde"));
+}
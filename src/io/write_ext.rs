@@ -0,0 +1,226 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::bytes::Buf;
+use crate::io::AsyncWrite;
+
+/// An extension trait which adds utility methods to [`AsyncWrite`] types.
+///
+/// # Examples
+///
+/// ```
+/// # async fn app() -> std::io::Result<()> {
+/// use tcio::io::AsyncWriteExt;
+///
+/// let mut io = Vec::new();
+///
+/// io.write_all(b"Hello World!").await?;
+/// io.flush().await?;
+/// assert_eq!(io, b"Hello World!");
+/// # Ok(())
+/// # }
+/// # assert!(matches!(
+/// #     std::pin::pin!(app())
+/// #         .poll(&mut std::task::Context::from_waker(std::task::Waker::noop())),
+/// #     std::task::Poll::Ready(Ok(()))
+/// # ));
+/// ```
+pub trait AsyncWriteExt: AsyncWrite {
+    /// Write bytes from `buf`.
+    ///
+    /// Resolves to the number of bytes written, which may be less than `buf.len()`.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If the future is dropped before completion, no bytes were
+    /// written.
+    #[inline]
+    fn write<'a>(&'a mut self, buf: &'a [u8]) -> Write<'a, Self>
+    where
+        Self: Unpin,
+    {
+        Write { writer: self, buf }
+    }
+
+    /// Write the entire `buf`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::ErrorKind::WriteZero`] if the writer no longer accepts bytes.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is **not** cancel safe. If the future is dropped before completion, some bytes
+    /// may have been written, and there is no way to tell how many.
+    ///
+    /// Use [`write_all_buf`][AsyncWriteExt::write_all_buf] to keep track of the written bytes.
+    #[inline]
+    fn write_all<'a>(&'a mut self, buf: &'a [u8]) -> WriteAll<'a, Self>
+    where
+        Self: Unpin,
+    {
+        WriteAll { writer: self, buf }
+    }
+
+    /// Write bytes from an instance of [`Buf`].
+    ///
+    /// Resolves to the number of bytes written, `buf` is advanced by the same amount.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. If the future is dropped before completion, no bytes were
+    /// written.
+    #[inline]
+    fn write_buf<'a, B: Buf>(&'a mut self, buf: &'a mut B) -> WriteBuf<'a, Self, B>
+    where
+        Self: Unpin,
+    {
+        WriteBuf { writer: self, buf }
+    }
+
+    /// Write the entire remaining bytes of an instance of [`Buf`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::ErrorKind::WriteZero`] if the writer no longer accepts bytes.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe in the sense that `buf` is advanced by the written bytes. If
+    /// the future is dropped before completion, the remaining bytes of `buf` is not yet written.
+    #[inline]
+    fn write_all_buf<'a, B: Buf>(&'a mut self, buf: &'a mut B) -> WriteAllBuf<'a, Self, B>
+    where
+        Self: Unpin,
+    {
+        WriteAllBuf { writer: self, buf }
+    }
+
+    /// Flush the writer, ensuring that any buffered bytes reach their destination.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe, flushing can be retried with a new future.
+    #[inline]
+    fn flush(&mut self) -> Flush<'_, Self>
+    where
+        Self: Unpin,
+    {
+        Flush { writer: self }
+    }
+
+    /// Shut down the writer, flushing any buffered bytes.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe, shutting down can be retried with a new future.
+    #[inline]
+    fn shutdown(&mut self) -> Shutdown<'_, Self>
+    where
+        Self: Unpin,
+    {
+        Shutdown { writer: self }
+    }
+}
+
+impl<T: AsyncWrite + ?Sized> AsyncWriteExt for T {}
+
+/// Future returned by [`AsyncWriteExt::write`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Write<'a, W: ?Sized> {
+    writer: &'a mut W,
+    buf: &'a [u8],
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized> Future for Write<'_, W> {
+    type Output = io::Result<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let me = self.get_mut();
+        Pin::new(&mut *me.writer).poll_write(me.buf, cx)
+    }
+}
+
+/// Future returned by [`AsyncWriteExt::write_all`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WriteAll<'a, W: ?Sized> {
+    writer: &'a mut W,
+    buf: &'a [u8],
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized> Future for WriteAll<'_, W> {
+    type Output = io::Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let me = self.get_mut();
+        // `buf` is advanced in place
+        Pin::new(&mut *me.writer).poll_write_all_buf(&mut me.buf, cx)
+    }
+}
+
+/// Future returned by [`AsyncWriteExt::write_buf`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WriteBuf<'a, W: ?Sized, B> {
+    writer: &'a mut W,
+    buf: &'a mut B,
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized, B: Buf> Future for WriteBuf<'_, W, B> {
+    type Output = io::Result<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let me = self.get_mut();
+        Pin::new(&mut *me.writer).poll_write_buf(&mut *me.buf, cx)
+    }
+}
+
+/// Future returned by [`AsyncWriteExt::write_all_buf`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WriteAllBuf<'a, W: ?Sized, B> {
+    writer: &'a mut W,
+    buf: &'a mut B,
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized, B: Buf> Future for WriteAllBuf<'_, W, B> {
+    type Output = io::Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let me = self.get_mut();
+        Pin::new(&mut *me.writer).poll_write_all_buf(&mut *me.buf, cx)
+    }
+}
+
+/// Future returned by [`AsyncWriteExt::flush`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Flush<'a, W: ?Sized> {
+    writer: &'a mut W,
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized> Future for Flush<'_, W> {
+    type Output = io::Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        Pin::new(&mut *self.get_mut().writer).poll_flush(cx)
+    }
+}
+
+/// Future returned by [`AsyncWriteExt::shutdown`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Shutdown<'a, W: ?Sized> {
+    writer: &'a mut W,
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized> Future for Shutdown<'_, W> {
+    type Output = io::Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        Pin::new(&mut *self.get_mut().writer).poll_shutdown(cx)
+    }
+}
@@ -7,6 +7,7 @@
 mod macros;
 
 pub mod bytes;
+pub mod codec;
 pub mod either;
 pub mod fmt;
 pub mod futures;
@@ -14,4 +15,4 @@ pub mod io;
 pub mod num;
 
 #[cfg(feature = "tokio")]
-mod tokio;
+pub mod tokio;
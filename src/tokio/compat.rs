@@ -0,0 +1,194 @@
+use std::io::{self, IoSlice};
+use std::mem::MaybeUninit;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+use tokio::io::{AsyncRead as TokioRead, AsyncWrite as TokioWrite, ReadBuf};
+
+use crate::bytes::BufMut;
+use crate::io::{AsyncRead, AsyncWrite};
+
+/// Adapter that implements tcio io traits for [`tokio`] io.
+///
+/// # Examples
+///
+/// ```
+/// # async fn app() -> std::io::Result<()> {
+/// use tcio::io::AsyncReadExt;
+/// use tcio::tokio::Compat;
+///
+/// // `&[u8]` here is using the tokio `AsyncRead` implementation
+/// let mut io = Compat::new(&b"Hello World!"[..]);
+/// let mut buf = [0u8; 5];
+///
+/// io.read_exact(&mut buf).await?;
+/// assert_eq!(&buf, b"Hello");
+/// # Ok(())
+/// # }
+/// # assert!(matches!(
+/// #     std::pin::pin!(app())
+/// #         .poll(&mut std::task::Context::from_waker(std::task::Waker::noop())),
+/// #     std::task::Poll::Ready(Ok(()))
+/// # ));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Compat<T> {
+    inner: T,
+}
+
+/// Adapter that implements [`tokio`] io traits for tcio io.
+///
+/// This allows tcio types, like [`BufReader`][crate::io::BufReader], to be used with tokio based
+/// libraries.
+#[derive(Debug, Clone, Default)]
+pub struct TokioCompat<T> {
+    inner: T,
+}
+
+macro_rules! compat_api {
+    ($name:ident) => {
+        impl<T> $name<T> {
+            #[doc = concat!("Creates new [`", stringify!($name), "`].")]
+            #[inline]
+            pub const fn new(inner: T) -> Self {
+                Self { inner }
+            }
+
+            /// Returns a shared reference to the underlying io.
+            #[inline]
+            pub const fn get_ref(&self) -> &T {
+                &self.inner
+            }
+
+            /// Returns a mutable reference to the underlying io.
+            #[inline]
+            pub const fn get_mut(&mut self) -> &mut T {
+                &mut self.inner
+            }
+
+            /// Returns a pinned mutable reference to the underlying io.
+            #[inline]
+            pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+                // SAFETY: self is pinned
+                // no `Drop`, nor manual `Unpin` implementation.
+                unsafe { self.map_unchecked_mut(|me| &mut me.inner) }
+            }
+
+            /// Consumes the adapter, returns the underlying io.
+            #[inline]
+            pub fn into_inner(self) -> T {
+                self.inner
+            }
+        }
+    };
+}
+
+compat_api!(Compat);
+compat_api!(TokioCompat);
+
+// ===== tokio to tcio =====
+
+impl<T: TokioRead> AsyncRead for Compat<T> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        mut buf: impl BufMut,
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        let mut read = ReadBuf::from(buf.chunk_mut());
+        ready!(self.get_pin_mut().poll_read(cx, &mut read))?;
+        let read = read.filled().len();
+        // SAFETY: `ReadBuf` guarantee that the filled is initialized
+        unsafe { buf.advance_mut(read) };
+        Poll::Ready(Ok(read))
+    }
+}
+
+impl<T: TokioWrite> AsyncWrite for Compat<T> {
+    #[inline]
+    fn poll_write(self: Pin<&mut Self>, buf: &[u8], cx: &mut Context) -> Poll<io::Result<usize>> {
+        self.get_pin_mut().poll_write(cx, buf)
+    }
+
+    #[inline]
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        self.get_pin_mut().poll_flush(cx)
+    }
+
+    #[inline]
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        self.get_pin_mut().poll_shutdown(cx)
+    }
+
+    #[inline]
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        bufs: &[IoSlice],
+        cx: &mut Context,
+    ) -> Poll<io::Result<usize>> {
+        self.get_pin_mut().poll_write_vectored(cx, bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+}
+
+// ===== tcio to tokio =====
+
+impl<T: AsyncRead> TokioRead for TokioCompat<T> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        // SAFETY: uninitialized bytes is never written
+        let unfilled = unsafe { buf.unfilled_mut() };
+        let len = unfilled.len();
+
+        // the read count is calculated from the advanced slice instead of the returned value, which
+        // is guaranteed by `BufMut` to be initialized
+        let mut dst: &mut [MaybeUninit<u8>] = unfilled;
+        ready!(self.get_pin_mut().poll_read(&mut dst, cx))?;
+        let read = len - dst.len();
+
+        // SAFETY: `BufMut` guarantee that the advanced bytes is initialized
+        unsafe { buf.assume_init(read) };
+        buf.advance(read);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T: AsyncWrite> TokioWrite for TokioCompat<T> {
+    #[inline]
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.get_pin_mut().poll_write(buf, cx)
+    }
+
+    #[inline]
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_pin_mut().poll_flush(cx)
+    }
+
+    #[inline]
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_pin_mut().poll_shutdown(cx)
+    }
+
+    #[inline]
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        self.get_pin_mut().poll_write_vectored(bufs, cx)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+}
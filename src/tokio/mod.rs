@@ -1,4 +1,10 @@
 //! Integration with [`tokio`] crate.
+//!
+//! tcio io traits is implemented for tokio [`TcpStream`] and [`UnixStream`]. For other tokio io,
+//! use the [`Compat`] adapter. To use tcio io with tokio based libraries, use the [`TokioCompat`]
+//! adapter.
+//!
+//! [`UnixStream`]: tokio::net::UnixStream
 use std::io::{self, IoSlice};
 use std::pin::Pin;
 use std::task::{Context, Poll, ready};
@@ -8,6 +14,13 @@ use tokio::net::TcpStream;
 use crate::bytes::{BufMut, UninitSlice};
 use crate::io::{AsyncRead, AsyncWrite};
 
+mod compat;
+
+pub use compat::{Compat, TokioCompat};
+
+#[cfg(test)]
+mod test;
+
 impl<'a> From<&'a mut UninitSlice> for ReadBuf<'a> {
     #[inline]
     fn from(value: &'a mut UninitSlice) -> Self {
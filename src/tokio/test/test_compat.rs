@@ -0,0 +1,66 @@
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+use tokio::io::{AsyncRead as TokioRead, AsyncWrite as TokioWrite, ReadBuf};
+
+use crate::bytes::BytesMut;
+use crate::io::{AsyncRead, AsyncWrite, BufReader, BufWriter};
+use crate::tokio::{Compat, TokioCompat};
+
+#[test]
+fn test_compat_read_write() {
+    let mut cx = Context::from_waker(Waker::noop());
+
+    let mut io = Compat::new(&b"Hello World!"[..]);
+    let mut buf = BytesMut::new();
+    let read = Pin::new(&mut io).poll_read(&mut buf, &mut cx);
+    assert!(matches!(read, Poll::Ready(Ok(12))));
+    assert_eq!(buf, b"Hello World!"[..]);
+
+    let mut io = Compat::new(Vec::new());
+    let write = Pin::new(&mut io).poll_write(b"Hello", &mut cx);
+    assert!(matches!(write, Poll::Ready(Ok(5))));
+    assert_eq!(io.into_inner(), b"Hello");
+}
+
+#[test]
+fn test_tokio_compat_read_write() {
+    let mut cx = Context::from_waker(Waker::noop());
+
+    let mut io = TokioCompat::new(BufReader::new(&b"Hello World!"[..]));
+    let mut storage = [0u8; 5];
+    let mut buf = ReadBuf::new(&mut storage);
+    let read = Pin::new(&mut io).poll_read(&mut cx, &mut buf);
+    assert!(matches!(read, Poll::Ready(Ok(()))));
+    assert_eq!(buf.filled(), b"Hello");
+
+    let mut buf = ReadBuf::new(&mut storage);
+    buf.advance(2);
+    let read = Pin::new(&mut io).poll_read(&mut cx, &mut buf);
+    assert!(matches!(read, Poll::Ready(Ok(()))));
+    assert_eq!(&buf.filled()[2..], b" Wo");
+
+    let mut io = TokioCompat::new(BufWriter::new(Vec::new()));
+    let write = Pin::new(&mut io).poll_write(&mut cx, b"Hello");
+    assert!(matches!(write, Poll::Ready(Ok(5))));
+    assert!(io.get_ref().inner().is_empty());
+    assert!(Pin::new(&mut io).poll_flush(&mut cx).is_ready());
+    assert_eq!(io.get_ref().inner(), b"Hello");
+}
+
+#[test]
+fn test_read_vectored() {
+    use crate::bytes::BufMut;
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut a = [0u8; 3];
+    let mut b = [0u8; 8];
+    let mut buf = (&mut a[..]).chain_mut(&mut b[..]);
+
+    // vectored read is done by `poll_read` on each uninitialized chunk
+    let mut io = Compat::new(&b"Hello World!"[..]);
+    assert!(!io.is_read_vectored());
+    let read = Pin::new(&mut io).poll_read_vectored(&mut buf, &mut cx);
+    assert!(matches!(read, Poll::Ready(Ok(11))));
+    assert_eq!(buf.remaining_mut(), 0);
+    assert_eq!((&a, &b), (b"Hel", b"lo World"));
+}